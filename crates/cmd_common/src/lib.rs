@@ -1,4 +1,5 @@
 use clap::Parser;
+use dancing_links_matrix::{IterativeAlgorithmXSolver, RestartStrategy};
 use log::Level;
 
 mod impls;
@@ -14,6 +15,32 @@ pub struct CommonArgs {
         help = "Set the log level, defaults to debug when in debug mode, info otherwise."
     )]
     pub log_level: Option<Level>,
+    #[arg(
+        long,
+        default_value_t = 0,
+        help = "Seed of the random choices of the solver."
+    )]
+    pub seed: u64,
+    #[arg(
+        long,
+        help = "Restart the search following the Luby sequence, with this number of nodes as unit."
+    )]
+    pub restart_unit: Option<usize>,
+}
+
+impl CommonArgs {
+    /// Applies the seed and the restart strategy to the solver.
+    pub fn configure<'a, T>(
+        &self,
+        solver: IterativeAlgorithmXSolver<'a, T>,
+    ) -> IterativeAlgorithmXSolver<'a, T> {
+        let solver = solver.with_seed(self.seed);
+
+        match self.restart_unit {
+            Some(unit) => solver.with_restarts(RestartStrategy::Luby { unit }),
+            None => solver,
+        }
+    }
 }
 
 pub fn init_log(args: &CommonArgs) {
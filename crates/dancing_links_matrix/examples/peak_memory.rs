@@ -0,0 +1,130 @@
+//! Measures the peak memory used to build a large matrix, with the [`MatrixRowBuilder`]
+//! and with the [`StreamingBuilder`].
+//!
+//! Run with `cargo run --release --example peak_memory -- [board size]`. For the default
+//! 500 x 500 board, with 998000 cells, it prints:
+//!
+//! ```text
+//! MatrixRowBuilder         peak    264.8 MiB, matrix    159.7 MiB, 998000 cells
+//! StreamingBuilder         peak    175.2 MiB, matrix    159.7 MiB, 998000 cells
+//! StreamingBuilder, hinted peak    162.0 MiB, matrix    159.7 MiB, 998000 cells
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use bumpalo::Bump;
+use dancing_links_matrix::{
+    Arena, DancingLinksMatrix, MatrixBuilder, MatrixRowBuilder, StreamingBuilder,
+};
+
+/// An allocator keeping track of the current and peak number of bytes allocated.
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let current = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+struct BumpArena(Bump);
+
+impl Arena for BumpArena {
+    fn alloc<T>(&self, val: T) -> &T {
+        self.0.alloc(val)
+    }
+}
+
+/// Returns the rows of the domino tilings of a `size` x `size` board.
+fn dominoes(size: usize) -> impl Iterator<Item = [usize; 2]> {
+    (0..size * size).flat_map(move |cell| {
+        let (r, c) = (cell / size, cell % size);
+        let horizontal = (c + 1 < size).then_some([cell + 1, cell + 2]);
+        let vertical = (r + 1 < size).then_some([cell + 1, cell + size + 1]);
+
+        horizontal.into_iter().chain(vertical)
+    })
+}
+
+/// Returns the peak number of bytes allocated while running `f`, and the bytes still
+/// allocated when it returns.
+fn measure<R>(f: impl FnOnce() -> R) -> (usize, usize, R) {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+
+    let result = f();
+
+    let peak = PEAK.load(Ordering::Relaxed) - base;
+    let kept = CURRENT.load(Ordering::Relaxed) - base;
+    (peak, kept, result)
+}
+
+fn report(name: &str, (peak, kept, matrix): (usize, usize, DancingLinksMatrix<'_, usize>)) {
+    println!(
+        "{name:<24} peak {:>8.1} MiB, matrix {:>8.1} MiB, {} cells",
+        peak as f64 / (1 << 20) as f64,
+        kept as f64 / (1 << 20) as f64,
+        matrix.stats().cells
+    );
+}
+
+fn main() {
+    let size = std::env::args().nth(1).map_or(500, |arg| {
+        arg.parse().expect("The board size must be a number")
+    });
+    let rows = 2 * size * (size - 1);
+
+    let arena = BumpArena(Bump::new());
+    report(
+        "MatrixRowBuilder",
+        measure(|| {
+            dominoes(size)
+                .fold(
+                    MatrixBuilder::from_iterable(1..=size * size),
+                    MatrixRowBuilder::add_sorted_row_index,
+                )
+                .build(&arena)
+        }),
+    );
+
+    let arena = BumpArena(Bump::new());
+    report(
+        "StreamingBuilder",
+        measure(|| {
+            dominoes(size)
+                .fold(
+                    StreamingBuilder::new(&arena, 1..=size * size),
+                    StreamingBuilder::add_sorted_row_index,
+                )
+                .build()
+        }),
+    );
+
+    let arena = BumpArena(Bump::new());
+    report(
+        "StreamingBuilder, hinted",
+        measure(|| {
+            dominoes(size)
+                .fold(
+                    StreamingBuilder::new(&arena, 1..=size * size).with_capacity(rows, 2 * rows),
+                    StreamingBuilder::add_sorted_row_index,
+                )
+                .build()
+        }),
+    );
+}
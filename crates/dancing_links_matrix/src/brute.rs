@@ -0,0 +1,124 @@
+//! A reference solver, simple enough to be checked by reading it.
+
+use std::collections::HashMap;
+
+use crate::{MatrixRowBuilder, solver::Solution};
+
+/// A solver trying subsets of rows directly on the rows of a [`MatrixRowBuilder`].
+///
+/// It does not use the dancing links, so it can cross-check [`IterativeAlgorithmXSolver`]
+/// and the encodings of problems on small matrices. At each step the first primary column
+/// not covered yet is taken, and every row covering it without conflicting with the rows
+/// chosen is tried, scanning all the rows. The search is exponential, and each step is
+/// linear in the size of the matrix.
+///
+/// [`IterativeAlgorithmXSolver`]: crate::IterativeAlgorithmXSolver
+pub struct BruteForceSolver<'b, T> {
+    builder: &'b MatrixRowBuilder<T>,
+    return_first: bool,
+}
+
+impl<'b, T> BruteForceSolver<'b, T> {
+    pub fn new(builder: &'b MatrixRowBuilder<T>, return_first: bool) -> Self {
+        Self {
+            builder,
+            return_first,
+        }
+    }
+
+    /// Returns the exact covers of the rows added to the builder.
+    ///
+    /// The solutions are returned in increasing lexicographic order of their rows, the
+    /// rows being numbered from 1 in the order they were added.
+    pub fn solve(&self) -> Vec<Solution<'b, T>> {
+        let columns = self.builder.columns();
+        let mut search = Search {
+            rows: self.builder.row_lists(),
+            primary: columns.iter().map(|column| column.primary).collect(),
+            covered: vec![false; columns.len()],
+            selection: Vec::new(),
+            found: Vec::new(),
+            return_first: self.return_first,
+        };
+        // The header is not a column to cover.
+        search.covered[0] = true;
+
+        search.search();
+        search.found.sort_unstable();
+
+        search
+            .found
+            .into_iter()
+            .map(|rows| self.solution(&search.rows, rows))
+            .collect()
+    }
+
+    fn solution(&self, row_lists: &[Vec<usize>], rows: Vec<usize>) -> Solution<'b, T> {
+        let columns = self.builder.columns();
+        let solution_map = rows
+            .into_iter()
+            .map(|row| {
+                let names = row_lists[row - 1]
+                    .iter()
+                    .filter_map(|&column| columns[column].name.get_name())
+                    .collect();
+
+                (row, names)
+            })
+            .collect::<HashMap<_, _>>();
+
+        Solution { solution_map }
+    }
+}
+
+struct Search {
+    /// The column indexes of each row.
+    rows: Vec<Vec<usize>>,
+    primary: Vec<bool>,
+    covered: Vec<bool>,
+    /// The rows chosen so far, numbered from 1.
+    selection: Vec<usize>,
+    found: Vec<Vec<usize>>,
+    return_first: bool,
+}
+
+impl Search {
+    /// Returns `false` when the search must stop.
+    fn search(&mut self) -> bool {
+        let column = (0..self.primary.len()).find(|&i| self.primary[i] && !self.covered[i]);
+
+        let Some(column) = column else {
+            let mut rows = self.selection.clone();
+            rows.sort_unstable();
+            self.found.push(rows);
+
+            return !self.return_first;
+        };
+
+        for index in 0..self.rows.len() {
+            let row = &self.rows[index];
+
+            if !row.contains(&column) || row.iter().any(|&i| self.covered[i]) {
+                continue;
+            }
+
+            self.set_covered(index, true);
+            self.selection.push(index + 1);
+            let go_on = self.search();
+            self.selection.pop();
+            self.set_covered(index, false);
+
+            if !go_on {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn set_covered(&mut self, index: usize, covered: bool) {
+        for &column in &self.rows[index] {
+            self.covered[column] = covered;
+        }
+    }
+}
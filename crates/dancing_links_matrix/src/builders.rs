@@ -4,7 +4,10 @@
 //!
 //! [`DancingLinksMatrix`]: crate::matrix::DancingLinksMatrix
 
+use std::cell::RefCell;
+
 use itertools::Itertools;
+use rand::{SeedableRng, rngs::StdRng};
 
 use crate::{
     arena::Arena,
@@ -105,8 +108,9 @@ impl<T> MatrixColBuilder<T> {
         let mut matrix = ProtoMatrix {
             row_count: 0,
             column_count: column_names.len(),
-            columns: Vec::new(),
-            cells: Vec::new(),
+            columns: Vec::with_capacity(column_names.len() + 1),
+            cells: Vec::with_capacity(column_names.len() + 1),
+            row_weights: Vec::new(),
         };
 
         let first_col_index = matrix.add_column(ColumnName::First, true);
@@ -205,12 +209,12 @@ impl<T> MatrixRowBuilder<T> {
             for val in row {
                 let mut added = false;
                 for column in col_iter.by_ref() {
-                    if let ColumnName::Other(name) = &column.name {
-                        if *name == val {
-                            to_add.push(column.index);
-                            added = true;
-                            break;
-                        }
+                    if let ColumnName::Other(name) = &column.name
+                        && *name == val
+                    {
+                        to_add.push(column.index);
+                        added = true;
+                        break;
                     }
                 }
 
@@ -258,9 +262,62 @@ impl<T> MatrixRowBuilder<T> {
         mx.link_horizontal(cur_index.unwrap(), start_index.unwrap());
 
         mx.row_count += 1;
+        mx.row_weights.push(1.0);
+        self
+    }
+
+    /// Sets the weight of the last row added to the [`MatrixRowBuilder`].
+    ///
+    /// Rows weigh `1.0` by default. The weight is used as the cost of the row by the
+    /// [`MinCostSolver`], so it must be finite and non negative.
+    ///
+    /// [`MatrixRowBuilder`]: MatrixRowBuilder
+    /// [`MinCostSolver`]: crate::cost::MinCostSolver
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "Row weight must be finite and non negative, got {weight}"
+        );
+
+        match self.matrix.row_weights.last_mut() {
+            Some(last) => *last = weight,
+            None => panic!("No rows were added"),
+        }
+
         self
     }
 
+    /// Reserves capacity for at least `rows` more rows, with `cells` cells in total.
+    pub(crate) fn reserve(&mut self, rows: usize, cells: usize) {
+        self.matrix.cells.reserve(cells);
+        self.matrix.row_weights.reserve(rows);
+    }
+
+    /// Returns the number of columns of the matrix being built.
+    pub(crate) fn column_count(&self) -> usize {
+        self.matrix.column_count
+    }
+
+    /// Returns the column indexes of each row added, in the order the rows were added.
+    ///
+    /// The lists are read from the cells as they were added, without following the links.
+    pub(crate) fn row_lists(&self) -> Vec<Vec<usize>> {
+        let mut rows = vec![Vec::new(); self.matrix.row_count];
+
+        for cell in &self.matrix.cells {
+            if let CellRow::Data(row) = cell.row {
+                rows[row.get() - 1].push(cell.column);
+            }
+        }
+
+        rows
+    }
+
+    /// Returns the columns added, the first one being the header of the matrix.
+    pub(crate) fn columns(&self) -> &[ProtoColumn<T>] {
+        &self.matrix.columns
+    }
+
     /// Build the [`DancingLinksMatrix`] from the columns and rows added.
     ///
     /// Receives an [`Arena`] to allocate memory for the matrix cells and columns.
@@ -324,6 +381,8 @@ impl<T> MatrixRowBuilder<T> {
             row_count: matrix.row_count,
             column_count: matrix.column_count,
             columns_queue,
+            row_weights: matrix.row_weights.into_boxed_slice(),
+            rng: RefCell::new(StdRng::seed_from_u64(0)),
         }
     }
 }
@@ -334,6 +393,7 @@ struct ProtoMatrix<T> {
     pub(crate) column_count: usize,
     pub(crate) columns: Vec<ProtoColumn<T>>,
     pub(crate) cells: Vec<ProtoCell>,
+    pub(crate) row_weights: Vec<f64>,
 }
 
 impl<T> ProtoMatrix<T> {
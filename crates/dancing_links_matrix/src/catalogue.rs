@@ -0,0 +1,290 @@
+//! Classic exact cover instances with known numbers of solutions.
+//!
+//! The instances are correctness oracles and performance references: a solver must find
+//! exactly [`Benchmark::solutions`] solutions on each of them.
+
+use itertools::Itertools;
+
+use crate::{ColumnSpec, MatrixBuilder, MatrixRowBuilder};
+
+/// The number of ways to place n queens on a n x n board, for n from 1 to 16 (OEIS A000170).
+const QUEENS: [u128; 16] = [
+    1, 0, 0, 2, 10, 4, 40, 92, 352, 724, 2680, 14200, 73712, 365596, 2279184, 14772512,
+];
+
+/// The same placements up to the symmetries of the board (OEIS A002562).
+const DISTINCT_QUEENS: [u128; 16] = [
+    1, 0, 0, 1, 2, 1, 6, 12, 46, 92, 341, 1787, 9233, 45752, 285053, 1846955,
+];
+
+/// The number of Langford pairings of 1..n, up to reversal, for n from 1 to 16
+/// (OEIS A014552).
+const LANGFORD: [u128; 16] = [
+    0, 0, 1, 1, 0, 0, 26, 150, 0, 0, 17792, 108144, 0, 0, 39809640, 326721800,
+];
+
+/// The rectangles that can be tiled with the 12 pentominoes, with their number of tilings
+/// up to the symmetries of the rectangle.
+const PENTOMINO_RECTANGLES: [(usize, usize, u128); 4] =
+    [(6, 10, 2339), (5, 12, 1010), (4, 15, 368), (3, 20, 2)];
+
+/// The 12 pentominoes, as (row, column) offsets of their cells.
+const PENTOMINOES: [(char, [(i32, i32); 5]); 12] = [
+    ('F', [(0, 1), (0, 2), (1, 0), (1, 1), (2, 1)]),
+    ('I', [(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]),
+    ('L', [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1)]),
+    ('N', [(0, 1), (1, 1), (2, 0), (2, 1), (3, 0)]),
+    ('P', [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)]),
+    ('T', [(0, 0), (0, 1), (0, 2), (1, 1), (2, 1)]),
+    ('U', [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]),
+    ('V', [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+    ('W', [(0, 0), (1, 0), (1, 1), (2, 1), (2, 2)]),
+    ('X', [(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]),
+    ('Y', [(0, 1), (1, 0), (1, 1), (2, 1), (3, 1)]),
+    ('Z', [(0, 0), (0, 1), (1, 1), (2, 1), (2, 2)]),
+];
+
+/// A well-known instance, with its number of solutions.
+pub struct Benchmark {
+    /// The name of the instance, like `queens-8`.
+    pub name: String,
+    /// The builder holding the rows of the instance, ready to be built.
+    pub builder: MatrixRowBuilder<String>,
+    /// The number of exact covers of the matrix.
+    pub solutions: u128,
+    /// The number of solutions up to the symmetries of the problem, when they are known.
+    ///
+    /// Published counts are usually given up to symmetry, and each of these solutions
+    /// corresponds to several exact covers of the matrix.
+    pub distinct: Option<u128>,
+}
+
+impl Benchmark {
+    /// Returns all the instances of the catalogue, from the quickest to solve to the
+    /// slowest for each problem.
+    ///
+    /// Some of them take minutes to solve: filter them by [`Self::solutions`] to keep the
+    /// quick ones.
+    pub fn catalogue() -> Vec<Benchmark> {
+        let mut benchmarks = vec![Self::sudoku_4x4()];
+
+        benchmarks.extend((1..=16).map(Self::n_queens));
+        benchmarks.extend((1..=16).map(Self::langford));
+        benchmarks.extend(
+            PENTOMINO_RECTANGLES
+                .iter()
+                .rev()
+                .map(|&(rows, cols, _)| Self::pentominoes(rows, cols)),
+        );
+        benchmarks.push(Self::pentominoes_with_hole());
+
+        benchmarks
+    }
+
+    /// The placements of `n` queens on a `n` x `n` board, none attacking another.
+    ///
+    /// The ranks and files are primary columns, the diagonals are secondary columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between 1 and 16.
+    pub fn n_queens(n: usize) -> Self {
+        assert!((1..=16).contains(&n), "Queens are known for n up to 16");
+
+        let mut columns = Vec::new();
+        columns.extend((0..n).map(|i| ColumnSpec::primary(format!("R{i}"))));
+        columns.extend((0..n).map(|i| ColumnSpec::primary(format!("F{i}"))));
+        columns.extend((0..2 * n - 1).map(|i| ColumnSpec::secondary(format!("A{i}"))));
+        columns.extend((0..2 * n - 1).map(|i| ColumnSpec::secondary(format!("B{i}"))));
+
+        let mut builder = MatrixBuilder::from_iterable(columns);
+        for (i, j) in (0..n).cartesian_product(0..n) {
+            builder = builder.add_sorted_row_index([
+                i + 1,
+                n + j + 1,
+                2 * n + i + j + 1,
+                5 * n - 1 - i + j,
+            ]);
+        }
+
+        Benchmark {
+            name: format!("queens-{n}"),
+            builder,
+            solutions: QUEENS[n - 1],
+            distinct: Some(DISTINCT_QUEENS[n - 1]),
+        }
+    }
+
+    /// The Langford pairings of 1..`n`: sequences of length `2n` where each number `k`
+    /// appears twice, with `k` numbers between its two occurrences.
+    ///
+    /// A sequence and its reversal are two solutions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between 1 and 16.
+    pub fn langford(n: usize) -> Self {
+        assert!(
+            (1..=16).contains(&n),
+            "Langford pairings are known for n up to 16"
+        );
+
+        let columns = (1..=n)
+            .map(|k| format!("N{k}"))
+            .chain((0..2 * n).map(|i| format!("P{i}")));
+
+        let mut builder = MatrixBuilder::from_iterable(columns);
+        for k in 1..=n {
+            for i in 0..2 * n - k - 1 {
+                builder = builder.add_sorted_row_index([k, n + i + 1, n + i + k + 2]);
+            }
+        }
+
+        Benchmark {
+            name: format!("langford-{n}"),
+            builder,
+            solutions: 2 * LANGFORD[n - 1],
+            distinct: Some(LANGFORD[n - 1]),
+        }
+    }
+
+    /// The tilings of a `rows` x `cols` rectangle with the 12 pentominoes, each used once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle is not 6x10, 5x12, 4x15 or 3x20, in either orientation.
+    pub fn pentominoes(rows: usize, cols: usize) -> Self {
+        let distinct = PENTOMINO_RECTANGLES
+            .iter()
+            .find(|&&(r, c, _)| (r, c) == (rows, cols) || (c, r) == (rows, cols))
+            .map(|&(_, _, distinct)| distinct)
+            .unwrap_or_else(|| panic!("A {rows}x{cols} rectangle can't be tiled"));
+
+        let board = (0..rows).cartesian_product(0..cols).collect_vec();
+
+        Benchmark {
+            name: format!("pentominoes-{rows}x{cols}"),
+            builder: pentomino_builder(&board),
+            // The rectangle has 4 symmetries.
+            solutions: 4 * distinct,
+            distinct: Some(distinct),
+        }
+    }
+
+    /// The tilings of an 8x8 board without its central 2x2 square with the 12
+    /// pentominoes, each used once.
+    pub fn pentominoes_with_hole() -> Self {
+        let board = (0..8)
+            .cartesian_product(0..8)
+            .filter(|&(r, c)| !((3..5).contains(&r) && (3..5).contains(&c)))
+            .collect_vec();
+
+        Benchmark {
+            name: "pentominoes-8x8-hole".to_string(),
+            builder: pentomino_builder(&board),
+            // The square has 8 symmetries.
+            solutions: 520,
+            distinct: Some(65),
+        }
+    }
+
+    /// The completions of an empty 4x4 sudoku grid, made of 2x2 boxes.
+    pub fn sudoku_4x4() -> Self {
+        let columns = ["r", "R", "C", "B"]
+            .into_iter()
+            .flat_map(|kind| {
+                (0..4)
+                    .cartesian_product(0..4)
+                    .map(move |(a, b)| format!("{kind}{a}{b}"))
+            })
+            .collect_vec();
+
+        let mut builder = MatrixBuilder::from_iterable(columns);
+        for (r, c, d) in itertools::iproduct!(0..4, 0..4, 0..4) {
+            let b = (r / 2) * 2 + c / 2;
+
+            builder = builder.add_sorted_row_index([
+                r * 4 + c + 1,
+                16 + r * 4 + d + 1,
+                32 + c * 4 + d + 1,
+                48 + b * 4 + d + 1,
+            ]);
+        }
+
+        Benchmark {
+            name: "sudoku-4x4".to_string(),
+            builder,
+            solutions: 288,
+            distinct: None,
+        }
+    }
+}
+
+/// Returns the builder placing each pentomino on the `board` cells, in every orientation.
+///
+/// The pieces are the first 12 columns, the cells the next ones, in the order of `board`.
+fn pentomino_builder(board: &[(usize, usize)]) -> MatrixRowBuilder<String> {
+    let columns = PENTOMINOES
+        .iter()
+        .map(|(piece, _)| piece.to_string())
+        .chain(board.iter().map(|(r, c)| format!("r{r}c{c}")));
+
+    let mut builder = MatrixBuilder::from_iterable(columns);
+    let rows = board.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+    let cols = board.iter().map(|&(_, c)| c + 1).max().unwrap_or(0);
+
+    for (piece, (_, cells)) in PENTOMINOES.iter().enumerate() {
+        for shape in orientations(cells) {
+            // The corner of a shape may not be a cell of the shape, so it may be on a hole.
+            for (r, c) in (0..rows).cartesian_product(0..cols) {
+                let row: Option<Vec<_>> = shape
+                    .iter()
+                    .map(|&(dr, dc)| {
+                        let cell = (r + dr, c + dc);
+                        let index = board.iter().position(|&other| other == cell)?;
+                        Some(PENTOMINOES.len() + index + 1)
+                    })
+                    .collect();
+
+                if let Some(row) = row {
+                    builder = builder.add_row_index(std::iter::once(piece + 1).chain(row));
+                }
+            }
+        }
+    }
+
+    builder
+}
+
+/// Returns the distinct rotations and reflections of a piece, each translated to have
+/// non negative offsets and sorted.
+fn orientations(cells: &[(i32, i32)]) -> Vec<Vec<(usize, usize)>> {
+    type Transform = fn((i32, i32)) -> (i32, i32);
+
+    let transforms: [Transform; 8] = [
+        |(r, c)| (r, c),
+        |(r, c)| (c, -r),
+        |(r, c)| (-r, -c),
+        |(r, c)| (-c, r),
+        |(r, c)| (r, -c),
+        |(r, c)| (-c, -r),
+        |(r, c)| (-r, c),
+        |(r, c)| (c, r),
+    ];
+
+    transforms
+        .iter()
+        .map(|transform| {
+            let moved = cells.iter().map(|&cell| transform(cell)).collect_vec();
+            let min_r = moved.iter().map(|&(r, _)| r).min().unwrap();
+            let min_c = moved.iter().map(|&(_, c)| c).min().unwrap();
+
+            moved
+                .into_iter()
+                .map(|(r, c)| ((r - min_r) as usize, (c - min_c) as usize))
+                .sorted()
+                .collect_vec()
+        })
+        .unique()
+        .collect()
+}
@@ -177,6 +177,18 @@ impl<'a, T> MatrixCell<'a, T> {
         &self.column().name
     }
 
+    /// Links `below` as the cell below the current cell.
+    pub fn link_down(&'a self, below: &'a MatrixCell<'a, T>) {
+        self.down.set(Some(below));
+        below.up.set(Some(self));
+    }
+
+    /// Links `right` as the cell to the right of the current cell.
+    pub fn link_right(&'a self, right: &'a MatrixCell<'a, T>) {
+        self.right.set(Some(right));
+        right.left.set(Some(self));
+    }
+
     /// Skips the cell to the left and right.
     ///
     /// It logically removes the cell from the row.
@@ -308,6 +320,10 @@ pub(crate) struct ColumnInfo<'a, T> {
     cell: MatrixCellPtr<'a, T>,
     /// If the column is a primary column.
     pub(crate) primary: bool,
+    /// If the column is currently covered.
+    ///
+    /// It is a cell since it will be mutated while solving.
+    covered: Cell<bool>,
 }
 
 impl<'a, T> ColumnInfo<'a, T> {
@@ -321,6 +337,7 @@ impl<'a, T> ColumnInfo<'a, T> {
             size: Cell::new(size),
             cell: Cell::new(None),
             primary,
+            covered: Cell::new(false),
         }
     }
 
@@ -362,6 +379,18 @@ impl<'a, T> ColumnInfo<'a, T> {
         self.size.get()
     }
 
+    /// Returns if the column is currently covered.
+    #[inline(always)]
+    pub fn covered(&self) -> bool {
+        self.covered.get()
+    }
+
+    /// Marks the column as covered or uncovered.
+    #[inline(always)]
+    pub fn set_covered(&self, covered: bool) {
+        self.covered.set(covered);
+    }
+
     /// Returns if the column has values.
     #[inline(always)]
     pub fn empty(&self) -> bool {
@@ -0,0 +1,30 @@
+//! A compact set of columns, used to identify the subproblems of a search.
+
+use crate::DancingLinksMatrix;
+
+/// A bitset of column indexes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ColumnSet(Box<[u64]>);
+
+impl ColumnSet {
+    /// Returns the set of the columns of the matrix not covered yet.
+    ///
+    /// Since a row is in the matrix only if none of its columns is covered, the set
+    /// identifies the remaining subproblem.
+    pub(crate) fn active<T>(matrix: &DancingLinksMatrix<'_, T>) -> Self {
+        let mut words = vec![0u64; matrix.columns.len().div_ceil(64)];
+
+        for column in matrix.columns.iter().skip(1) {
+            if !column.covered() {
+                words[column.index / 64] |= 1 << (column.index % 64);
+            }
+        }
+
+        ColumnSet(words.into_boxed_slice())
+    }
+
+    /// Returns the memory used by the set, in bytes.
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        size_of::<Self>() + size_of_val(&*self.0)
+    }
+}
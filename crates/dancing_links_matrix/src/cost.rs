@@ -0,0 +1,146 @@
+//! Minimum cost exact cover.
+//!
+//! Each row of the matrix has a weight, set with [`MatrixRowBuilder::with_weight`],
+//! and the cost of a solution is the sum of the weights of its rows.
+//!
+//! [`MatrixRowBuilder::with_weight`]: crate::MatrixRowBuilder::with_weight
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::{CellIteratorDir, ColumnChoice, ColumnIteratorDir},
+    solver::{SearchFlow, SearchHooks, Solution, search},
+};
+
+/// A solution together with its cost.
+pub struct CostSolution<'a, T> {
+    pub solution: Solution<'a, T>,
+    pub cost: f64,
+}
+
+/// A solver looking for the exact cover with the minimum cost.
+///
+/// It runs a branch and bound on top of Algorithm X: a branch is pruned when the
+/// cost of the rows chosen so far, plus a lower bound of the cost needed to cover
+/// the remaining columns, is not better than the best solution found so far.
+///
+/// The lower bound splits the weight of each row evenly among its primary columns,
+/// and sums, for each remaining column, the cheapest share among its rows.
+pub struct MinCostSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    /// The weight of each row divided by its number of primary columns, indexed by row index - 1.
+    shares: Vec<f64>,
+}
+
+impl<'a, T> MinCostSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        let shares = matrix
+            .primary_row_lengths()
+            .into_iter()
+            .enumerate()
+            .map(|(i, len)| match len {
+                0 => 0.0,
+                len => matrix.row_weight(i + 1) / len as f64,
+            })
+            .collect();
+
+        Self { matrix, shares }
+    }
+
+    /// Returns the solution with the minimum cost, or `None` if there is no exact cover.
+    pub fn solve(&self) -> Option<CostSolution<'a, T>> {
+        self.solve_with(|_| {})
+    }
+
+    /// Returns the solution with the minimum cost, or `None` if there is no exact cover.
+    ///
+    /// `on_improvement` is called every time a solution better than the previous ones
+    /// is found, so that the search can be monitored while it runs.
+    pub fn solve_with(
+        &self,
+        on_improvement: impl FnMut(&CostSolution<'a, T>),
+    ) -> Option<CostSolution<'a, T>> {
+        let mut hooks = BranchAndBound {
+            shares: &self.shares,
+            best: None,
+            on_improvement,
+        };
+
+        search(&self.matrix, ColumnChoice::Min, &mut hooks);
+
+        hooks.best
+    }
+}
+
+struct BranchAndBound<'s, 'a, T, F> {
+    shares: &'s [f64],
+    best: Option<CostSolution<'a, T>>,
+    on_improvement: F,
+}
+
+impl<'a, T, F> BranchAndBound<'_, 'a, T, F> {
+    /// Computes a lower bound of the cost needed to cover the remaining primary columns.
+    fn lower_bound(&self, matrix: &DancingLinksMatrix<'a, T>) -> f64 {
+        let mut bound = 0.0;
+
+        for column in matrix.iterate_columns(matrix.first_column(), ColumnIteratorDir::Right, false)
+        {
+            let cheapest = matrix
+                .iterate_cells(column.cell(), CellIteratorDir::Down, false)
+                .map(|cell| self.shares[usize::from(cell.row) - 1])
+                .min_by(f64::total_cmp);
+
+            match cheapest {
+                Some(cheapest) => bound += cheapest,
+                None => return f64::INFINITY,
+            }
+        }
+
+        bound
+    }
+}
+
+impl<'a, T, F> SearchHooks<'a, T> for BranchAndBound<'_, 'a, T, F>
+where
+    F: FnMut(&CostSolution<'a, T>),
+{
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        match &self.best {
+            Some(best) => selection_cost(matrix, selection) + self.lower_bound(matrix) < best.cost,
+            None => true,
+        }
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        let cost = selection_cost(matrix, selection);
+
+        if self.best.as_ref().is_none_or(|best| cost < best.cost) {
+            let improved = CostSolution {
+                solution: Solution::from_rows(matrix, selection),
+                cost,
+            };
+            (self.on_improvement)(&improved);
+            self.best = Some(improved);
+        }
+
+        SearchFlow::Continue
+    }
+}
+
+fn selection_cost<T>(
+    matrix: &DancingLinksMatrix<'_, T>,
+    selection: &[MatrixCellRef<'_, T>],
+) -> f64 {
+    selection
+        .iter()
+        .map(|row| matrix.row_weight(row.row.into()))
+        .sum()
+}
@@ -0,0 +1,270 @@
+//! Counting exact covers with memoized subproblems.
+//!
+//! Like when building a [`Zdd`], the number of solutions of each subproblem is cached,
+//! keyed by the set of its active columns, so that a residual board reached along many
+//! paths is only counted once. Unlike a [`Zdd`], only the counts are kept, and the cache
+//! can be bounded in memory.
+//!
+//! The counts generalize to weighted model counting: each row gets a weight in a
+//! [`Semiring`], and the weights of the rows of each solution are multiplied, then summed
+//! over all the solutions, without enumerating them.
+//!
+//! [`Zdd`]: crate::Zdd
+
+use std::collections::HashMap;
+
+use crate::{
+    DancingLinksMatrix, Semiring,
+    column_set::ColumnSet,
+    matrix::CellIteratorDir,
+    solver::{cover_row, uncover_row},
+};
+
+/// Statistics about the cache of a [`MemoCounter`] or a [`WeightedCounter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CountStats {
+    /// The number of subproblems found in the cache.
+    pub hits: usize,
+    /// The number of subproblems counted.
+    pub misses: usize,
+    /// The number of entries evicted from the cache.
+    pub evictions: usize,
+    /// The maximum memory used by the cache, in bytes.
+    pub peak_bytes: usize,
+}
+
+/// A solver counting the exact covers of a matrix, caching the count of each subproblem.
+///
+/// The key of a subproblem is the set of the columns not covered yet, secondary columns
+/// included, since they also restrict the rows left.
+///
+/// When the cache grows over its memory limit, the least recently used half of its
+/// entries is evicted. Evicted subproblems are counted again when reached later, so the
+/// limit only trades time for memory.
+pub struct MemoCounter<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    memory_limit: usize,
+}
+
+impl<'a, T> MemoCounter<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        Self {
+            matrix,
+            memory_limit: usize::MAX,
+        }
+    }
+
+    /// Limits the memory used by the cache to about `bytes` bytes.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = bytes;
+        self
+    }
+
+    /// Returns the number of exact covers.
+    pub fn count(&self) -> u128 {
+        self.count_with_stats().0
+    }
+
+    /// Returns the number of exact covers, together with statistics about the cache.
+    pub fn count_with_stats(&self) -> (u128, CountStats) {
+        let weights = vec![1; self.matrix.row_count()];
+
+        Cache::new(&self.matrix, &weights, self.memory_limit).run()
+    }
+}
+
+/// A solver summing, over all the exact covers, the product of the weights of their rows.
+///
+/// It uses the same cache as [`MemoCounter`], the subproblems storing their sum of weights
+/// instead of their number of solutions.
+pub struct WeightedCounter<'a, T, S> {
+    matrix: DancingLinksMatrix<'a, T>,
+    /// The weight of each row, indexed by row index - 1.
+    weights: Vec<S>,
+    memory_limit: usize,
+}
+
+impl<'a, T, S: Semiring> WeightedCounter<'a, T, S> {
+    /// Creates a counter with the given weights, one for each row in the order the rows
+    /// were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of weights is not the number of rows.
+    pub fn new(matrix: DancingLinksMatrix<'a, T>, weights: impl IntoIterator<Item = S>) -> Self {
+        let weights: Vec<_> = weights.into_iter().collect();
+        assert_eq!(
+            weights.len(),
+            matrix.row_count(),
+            "There must be one weight for each row"
+        );
+
+        Self {
+            matrix,
+            weights,
+            memory_limit: usize::MAX,
+        }
+    }
+
+    /// Limits the memory used by the cache to about `bytes` bytes.
+    ///
+    /// The memory owned by the weights stored in the cache is counted with
+    /// [`Semiring::heap_size`].
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = bytes;
+        self
+    }
+
+    /// Returns the sum of the weights of the exact covers.
+    pub fn count(&self) -> S {
+        self.count_with_stats().0
+    }
+
+    /// Returns the sum of the weights of the exact covers, together with statistics about
+    /// the cache.
+    pub fn count_with_stats(&self) -> (S, CountStats) {
+        Cache::new(&self.matrix, &self.weights, self.memory_limit).run()
+    }
+}
+
+impl<'a, T> WeightedCounter<'a, T, f64> {
+    /// Creates a counter using the weights set with [`MatrixRowBuilder::with_weight`].
+    ///
+    /// [`MatrixRowBuilder::with_weight`]: crate::MatrixRowBuilder::with_weight
+    pub fn from_row_weights(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        let weights: Vec<_> = (1..=matrix.row_count())
+            .map(|row| matrix.row_weight(row))
+            .collect();
+
+        Self::new(matrix, weights)
+    }
+}
+
+struct Entry<S> {
+    count: S,
+    /// The tick of the last use of the entry.
+    used: u64,
+}
+
+struct Cache<'m, 'a, T, S> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    weights: &'m [S],
+    entries: HashMap<ColumnSet, Entry<S>>,
+    /// The memory used by the entries, in bytes.
+    bytes: usize,
+    memory_limit: usize,
+    tick: u64,
+    stats: CountStats,
+}
+
+impl<'m, 'a, T, S: Semiring> Cache<'m, 'a, T, S> {
+    fn new(matrix: &'m DancingLinksMatrix<'a, T>, weights: &'m [S], memory_limit: usize) -> Self {
+        Cache {
+            matrix,
+            weights,
+            entries: HashMap::new(),
+            bytes: 0,
+            memory_limit,
+            tick: 0,
+            stats: CountStats::default(),
+        }
+    }
+
+    fn run(mut self) -> (S, CountStats) {
+        let count = self.count();
+
+        (count, self.stats)
+    }
+
+    fn count(&mut self) -> S {
+        let matrix = self.matrix;
+
+        if matrix.all_covered() {
+            return S::one();
+        }
+
+        self.tick += 1;
+
+        let key = ColumnSet::active(matrix);
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.used = self.tick;
+            self.stats.hits += 1;
+            return entry.count.clone();
+        }
+
+        self.stats.misses += 1;
+
+        let column = matrix.min_column();
+        let mut total = S::zero();
+
+        if !column.empty() {
+            matrix.cover(column);
+
+            for row in matrix.iterate_cells(column.cell(), CellIteratorDir::Down, false) {
+                cover_row(matrix, row);
+                let weight = &self.weights[usize::from(row.row) - 1];
+                total = total.add(&weight.mul(&self.count()));
+                uncover_row(matrix, row);
+            }
+
+            matrix.uncover(column);
+        }
+
+        self.insert(key, total.clone());
+        total
+    }
+
+    fn insert(&mut self, key: ColumnSet, count: S) {
+        let entry = Entry {
+            count,
+            used: self.tick,
+        };
+        let size = entry_size(&key, &entry);
+
+        if self.bytes + size > self.memory_limit {
+            self.evict();
+
+            if self.bytes + size > self.memory_limit {
+                return;
+            }
+        }
+
+        self.bytes += size;
+        self.stats.peak_bytes = self.stats.peak_bytes.max(self.bytes);
+        self.entries.insert(key, entry);
+    }
+
+    /// Evicts the least recently used half of the entries.
+    fn evict(&mut self) {
+        let mut used: Vec<_> = self.entries.values().map(|entry| entry.used).collect();
+        if used.is_empty() {
+            return;
+        }
+
+        let middle = used.len() / 2;
+        let (_, &mut threshold, _) = used.select_nth_unstable(middle);
+
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| entry.used > threshold);
+
+        self.stats.evictions += before - self.entries.len();
+        self.bytes = self
+            .entries
+            .iter()
+            .map(|(key, entry)| entry_size(key, entry))
+            .sum();
+    }
+}
+
+/// Returns an estimate of the memory used by an entry of the cache, in bytes.
+fn entry_size<S: Semiring>(key: &ColumnSet, entry: &Entry<S>) -> usize {
+    // The map stores a control byte with each slot, and keeps at least 1/8 of its slots
+    // empty.
+    let slot = (size_of::<(ColumnSet, Entry<S>)>() + 1) * 8 / 7;
+
+    slot + key.size_in_bytes() - size_of::<ColumnSet>() + entry.count.heap_size()
+}
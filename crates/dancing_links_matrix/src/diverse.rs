@@ -0,0 +1,178 @@
+//! Diverse sets of exact covers.
+//!
+//! Depth-first search returns solutions that usually differ in a single row. The solvers
+//! here look for solutions far from each other instead, measuring the distance between two
+//! solutions as the number of rows in only one of them.
+
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::ColumnChoice,
+    solver::{SearchFlow, SearchHooks, Solution, search},
+};
+
+/// A solver returning several exact covers that differ from each other.
+///
+/// Each solution is found by a new search, pruning the branches that cannot lead to a
+/// solution far enough from the ones already found: a branch whose rows overlap too much
+/// with a previous solution is cut, since each remaining primary column adds at most one
+/// row.
+pub struct DiverseSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    /// Rows that are never part of a solution.
+    forbidden: BTreeSet<usize>,
+}
+
+impl<'a, T> DiverseSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        Self {
+            matrix,
+            forbidden: BTreeSet::new(),
+        }
+    }
+
+    /// Never uses the rows with the given indexes, numbered from 1.
+    ///
+    /// Returns `self`, for chaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row does not exist.
+    pub fn with_forbidden_rows(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        for row in rows {
+            assert!(
+                self.matrix.row_cell(row).is_some(),
+                "Row {row} does not exist"
+            );
+            self.forbidden.insert(row);
+        }
+
+        self
+    }
+
+    /// Returns up to `k` solutions, each one differing from all the previous ones by at
+    /// least `distance` rows.
+    ///
+    /// Each solution is the first one found by the search satisfying the constraint, so
+    /// fewer than `k` solutions are returned when no other solution is far enough.
+    pub fn solve_min_distance(&self, k: usize, distance: usize) -> Vec<Solution<'a, T>> {
+        self.solve_with(k, distance.max(1), true)
+    }
+
+    /// Returns up to `k` solutions, chosen greedily: each solution maximizes its minimum
+    /// distance to the previous ones.
+    ///
+    /// Fewer than `k` solutions are returned when there are fewer than `k` solutions.
+    pub fn solve_max_diverse(&self, k: usize) -> Vec<Solution<'a, T>> {
+        self.solve_with(k, 1, false)
+    }
+
+    fn solve_with(&self, k: usize, distance: usize, first: bool) -> Vec<Solution<'a, T>> {
+        let forbidden = self
+            .forbidden
+            .iter()
+            .map(|&row| self.matrix.row_cell(row).unwrap())
+            .collect_vec();
+
+        for &row in &forbidden {
+            self.matrix.hide_row(row);
+        }
+
+        let mut previous: Vec<Vec<usize>> = Vec::new();
+
+        while previous.len() < k {
+            let mut hooks = FarFrom {
+                previous: &previous,
+                required: distance,
+                first,
+                best: None,
+            };
+
+            search(&self.matrix, ColumnChoice::Min, &mut hooks);
+
+            match hooks.best {
+                Some(rows) => previous.push(rows),
+                None => break,
+            }
+        }
+
+        for &row in forbidden.iter().rev() {
+            self.matrix.unhide_row(row);
+        }
+
+        previous
+            .into_iter()
+            .map(|rows| self.matrix.solution_from_rows(rows))
+            .collect()
+    }
+}
+
+/// Returns the number of rows of `rows` contained in the sorted list `other`.
+fn overlap(rows: impl IntoIterator<Item = usize>, other: &[usize]) -> usize {
+    rows.into_iter()
+        .filter(|row| other.binary_search(row).is_ok())
+        .count()
+}
+
+/// Hooks looking for a solution at distance at least `required` from all the `previous`
+/// solutions, raising `required` after each solution unless `first` is set.
+struct FarFrom<'p> {
+    /// The previous solutions, as sorted lists of rows.
+    previous: &'p [Vec<usize>],
+    required: usize,
+    first: bool,
+    best: Option<Vec<usize>>,
+}
+
+impl FarFrom<'_> {
+    fn rows<'a, T>(selection: &[MatrixCellRef<'a, T>]) -> impl Iterator<Item = usize> {
+        selection.iter().map(|cell| cell.row.into())
+    }
+}
+
+impl<'a, T> SearchHooks<'a, T> for FarFrom<'_> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        // A solution has at most one row for each remaining primary column.
+        let max_rows = selection.len() + matrix.uncovered_count();
+
+        self.previous.iter().all(|other| {
+            let common = overlap(Self::rows(selection), other);
+            (max_rows + other.len()).saturating_sub(2 * common) >= self.required
+        })
+    }
+
+    fn solution(
+        &mut self,
+        _matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        let rows = Self::rows(selection).sorted().collect_vec();
+
+        let distance = self
+            .previous
+            .iter()
+            .map(|other| rows.len() + other.len() - 2 * overlap(rows.iter().copied(), other))
+            .min();
+
+        match distance {
+            Some(distance) if distance < self.required => SearchFlow::Continue,
+            Some(distance) if !self.first => {
+                self.best = Some(rows);
+                self.required = distance + 1;
+                SearchFlow::Continue
+            }
+            _ => {
+                self.best = Some(rows);
+                SearchFlow::Stop
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Reversible primitives to write custom search algorithms.
+//!
+//! An [`Explorer`] exposes the operations Algorithm X is made of: choosing a row covers
+//! its columns and removes the rows sharing a column with it, unchoosing it restores them.
+//! Choices must be undone in the reverse order they were made, which lets best-first,
+//! beam or any other search strategy be built on top of the dancing links, by replaying
+//! choices from a common prefix.
+
+use std::{error::Error, fmt};
+
+use crate::{
+    DancingLinksMatrix,
+    cells::{ColumnRef, MatrixCellRef},
+    matrix::{CellIteratorDir, ColumnIteratorDir},
+    solver::{cover_row, uncover_row},
+};
+
+/// An error returned by [`Explorer::choose`] and [`Explorer::unchoose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChooseError {
+    /// There is no row with this index.
+    UnknownRow(usize),
+    /// The row shares a column with a row already chosen.
+    Conflict(usize),
+    /// The row is not the last row chosen.
+    NotLastChosen(usize),
+}
+
+impl fmt::Display for ChooseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChooseError::UnknownRow(row) => write!(f, "row {row} does not exist"),
+            ChooseError::Conflict(row) => {
+                write!(f, "row {row} shares a column with a chosen row")
+            }
+            ChooseError::NotLastChosen(row) => write!(f, "row {row} is not the last row chosen"),
+        }
+    }
+}
+
+impl Error for ChooseError {}
+
+/// A handle choosing and unchoosing rows of a matrix.
+///
+/// Rows are numbered from 1, columns are numbered from 1 in the order they were added to
+/// the builder. The rows still chosen are unchosen when the explorer is dropped, restoring
+/// the matrix.
+pub struct Explorer<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    chosen: Vec<MatrixCellRef<'a, T>>,
+}
+
+impl<'m, 'a, T> Explorer<'m, 'a, T> {
+    pub(crate) fn new(matrix: &'m DancingLinksMatrix<'a, T>) -> Self {
+        Explorer {
+            matrix,
+            chosen: Vec::new(),
+        }
+    }
+
+    /// Chooses the row with index `row`, covering its columns.
+    pub fn choose(&mut self, row: usize) -> Result<(), ChooseError> {
+        let cell = self
+            .matrix
+            .row_cell(row)
+            .ok_or(ChooseError::UnknownRow(row))?;
+
+        if !self.matrix.is_row_active(cell) {
+            return Err(ChooseError::Conflict(row));
+        }
+
+        self.matrix.cover(cell.column());
+        cover_row(self.matrix, cell);
+        self.chosen.push(cell);
+
+        Ok(())
+    }
+
+    /// Unchooses the row with index `row`, which must be the last row chosen.
+    ///
+    /// Nothing is unchosen if `row` is not the last row chosen.
+    pub fn unchoose(&mut self, row: usize) -> Result<(), ChooseError> {
+        if self.chosen.last().map(|cell| usize::from(cell.row)) != Some(row) {
+            return Err(ChooseError::NotLastChosen(row));
+        }
+
+        self.undo();
+        Ok(())
+    }
+
+    /// Unchooses the last row chosen, and returns its index, or `None` if no row is chosen.
+    pub fn undo(&mut self) -> Option<usize> {
+        let cell = self.chosen.pop()?;
+
+        uncover_row(self.matrix, cell);
+        self.matrix.uncover(cell.column());
+
+        Some(cell.row.into())
+    }
+
+    /// Returns the rows chosen, in the order they were chosen.
+    pub fn chosen(&self) -> Vec<usize> {
+        self.chosen.iter().map(|cell| cell.row.into()).collect()
+    }
+
+    /// Returns `true` if all the primary columns are covered, the rows chosen forming an
+    /// exact cover.
+    pub fn is_solved(&self) -> bool {
+        self.matrix.all_covered()
+    }
+
+    /// Returns `true` if the row can be chosen, none of its columns being covered.
+    ///
+    /// Returns `false` if there is no row with this index.
+    pub fn is_row_active(&self, row: usize) -> bool {
+        self.matrix
+            .row_cell(row)
+            .is_some_and(|cell| self.matrix.is_row_active(cell))
+    }
+
+    /// Returns the primary columns not covered yet, in the order they were added.
+    pub fn active_columns(&self) -> impl Iterator<Item = usize> + use<'a, T> {
+        let matrix = self.matrix;
+
+        matrix
+            .iterate_columns(matrix.first_column(), ColumnIteratorDir::Right, false)
+            .map(|column| column.index)
+    }
+
+    /// Returns the primary column not covered yet with the fewest rows, or `None` if all
+    /// the primary columns are covered.
+    pub fn min_column(&self) -> Option<usize> {
+        (!self.is_solved()).then(|| self.matrix.min_column().index)
+    }
+
+    /// Returns the number of rows that can still cover the column.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column with this index.
+    pub fn column_size(&self, column: usize) -> usize {
+        self.column(column).size()
+    }
+
+    /// Returns the rows that can still cover the column, from top to bottom.
+    ///
+    /// The rows of a covered column are the ones left when it was covered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column with this index.
+    pub fn column_rows(&self, column: usize) -> impl Iterator<Item = usize> + use<'a, T> {
+        let column = self.column(column);
+
+        self.matrix
+            .iterate_cells(column.cell(), CellIteratorDir::Down, false)
+            .map(|cell| cell.row.into())
+    }
+
+    /// Returns the columns of the row, in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no row with this index.
+    pub fn row_columns(&self, row: usize) -> impl Iterator<Item = usize> + use<'a, T> {
+        let cell = self
+            .matrix
+            .row_cell(row)
+            .unwrap_or_else(|| panic!("Row {row} does not exist"));
+
+        self.matrix
+            .iterate_cells(cell, CellIteratorDir::Right, true)
+            .map(|cell| cell.column().index)
+    }
+
+    fn column(&self, column: usize) -> ColumnRef<'a, T> {
+        assert!(
+            (1..=self.matrix.column_count()).contains(&column),
+            "Column {column} does not exist"
+        );
+
+        self.matrix.columns[column]
+    }
+}
+
+impl<T> Drop for Explorer<'_, '_, T> {
+    fn drop(&mut self) {
+        while self.undo().is_some() {}
+    }
+}
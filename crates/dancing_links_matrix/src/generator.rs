@@ -0,0 +1,176 @@
+//! Random exact cover instances with known solutions, for tests and benchmarks.
+
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use crate::{ColumnSpec, MatrixBuilder, MatrixRowBuilder};
+
+/// A generator of random instances, where some solutions are planted.
+///
+/// The columns are named from 1 to the number of columns, the secondary columns being
+/// the last ones. Each planted solution is a random partition of the primary columns into
+/// rows, with some secondary columns spread over its rows. The other rows are random,
+/// each column being in a row with probability `density`. The rows are then shuffled.
+///
+/// The random rows may create more solutions than the planted ones.
+#[derive(Debug, Clone)]
+pub struct InstanceGenerator {
+    columns: usize,
+    secondary: usize,
+    density: f64,
+    rows: usize,
+    planted: usize,
+    seed: u64,
+}
+
+/// An instance made by [`InstanceGenerator::generate`].
+pub struct GeneratedInstance {
+    /// The builder holding the rows of the instance, ready to be built.
+    pub builder: MatrixRowBuilder<usize>,
+    /// The planted solutions, as sorted lists of row indexes, numbered from 1.
+    pub planted: Vec<Vec<usize>>,
+}
+
+impl InstanceGenerator {
+    /// Creates a generator of instances with `columns` primary columns, and by default a
+    /// density of `0.3`, as many random rows as columns, one planted solution and seed `0`.
+    pub fn new(columns: usize) -> Self {
+        assert!(columns > 0, "There must be at least one column");
+
+        Self {
+            columns,
+            secondary: 0,
+            density: 0.3,
+            rows: columns,
+            planted: 1,
+            seed: 0,
+        }
+    }
+
+    /// Makes the last `secondary` columns secondary.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_secondary(mut self, secondary: usize) -> Self {
+        assert!(
+            secondary < self.columns,
+            "There must be at least one primary column"
+        );
+
+        self.secondary = secondary;
+        self
+    }
+
+    /// Sets the probability of each column to be in a random row, which is also the
+    /// expected fraction of the primary columns in a planted row.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_density(mut self, density: f64) -> Self {
+        assert!(
+            density > 0.0 && density <= 1.0,
+            "Density must be in (0, 1], got {density}"
+        );
+
+        self.density = density;
+        self
+    }
+
+    /// Sets the number of random rows, added to the rows of the planted solutions.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_random_rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the number of planted solutions, which share no row.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_planted(mut self, planted: usize) -> Self {
+        self.planted = planted;
+        self
+    }
+
+    /// Sets the seed of the generator, the same seed always giving the same instance.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generates an instance.
+    pub fn generate(&self) -> GeneratedInstance {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        // The rows, with the planted solution they belong to.
+        let mut rows: Vec<(Vec<usize>, Option<usize>)> = Vec::new();
+
+        for solution in 0..self.planted {
+            for row in self.planted_rows(&mut rng) {
+                rows.push((row, Some(solution)));
+            }
+        }
+
+        for _ in 0..self.rows {
+            rows.push((self.random_row(&mut rng), None));
+        }
+
+        rows.shuffle(&mut rng);
+
+        let mut builder = MatrixBuilder::from_iterable((1..=self.columns).map(|column| {
+            if column <= self.primary() {
+                ColumnSpec::primary(column)
+            } else {
+                ColumnSpec::secondary(column)
+            }
+        }));
+        let mut planted = vec![Vec::new(); self.planted];
+
+        for (index, (row, solution)) in rows.into_iter().enumerate() {
+            builder = builder.add_sorted_row_index(row);
+
+            if let Some(solution) = solution {
+                planted[solution].push(index + 1);
+            }
+        }
+
+        GeneratedInstance { builder, planted }
+    }
+
+    fn primary(&self) -> usize {
+        self.columns - self.secondary
+    }
+
+    /// Returns the rows of a planted solution, as sorted column indexes.
+    fn planted_rows(&self, rng: &mut StdRng) -> Vec<Vec<usize>> {
+        let parts = ((1.0 / self.density).round() as usize).clamp(1, self.primary());
+        let mut rows = vec![Vec::new(); parts];
+
+        for column in 1..=self.primary() {
+            rows[rng.gen_range(0..parts)].push(column);
+        }
+
+        rows.retain(|row| !row.is_empty());
+
+        for column in self.primary() + 1..=self.columns {
+            if rng.gen_bool(self.density) {
+                let part = rng.gen_range(0..rows.len());
+                rows[part].push(column);
+            }
+        }
+
+        rows
+    }
+
+    /// Returns a random non empty row, as sorted column indexes.
+    fn random_row(&self, rng: &mut StdRng) -> Vec<usize> {
+        let row: Vec<_> = (1..=self.columns)
+            .filter(|_| rng.gen_bool(self.density))
+            .collect();
+
+        if row.is_empty() {
+            vec![rng.gen_range(1..=self.columns)]
+        } else {
+            row
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Creating a matrix from the usual representations of 0/1 matrices.
+//!
+//! The rows and columns of the imported matrices are numbered from 0, like in the
+//! libraries these representations usually come from. Errors use the same numbering.
+
+use std::{error::Error, fmt};
+
+use itertools::Itertools;
+
+use crate::{ColumnSpec, MatrixBuilder, MatrixRowBuilder};
+
+/// An error found while importing a matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportError {
+    /// The matrix has no column.
+    NoColumns,
+    /// A row has no column, while every row must have at least one.
+    EmptyRow { row: usize },
+    /// A row of a dense matrix does not have one entry per column.
+    RowLength { row: usize, len: usize },
+    /// A row has a column that does not exist.
+    ColumnOutOfRange { row: usize, column: usize },
+    /// A row has the same column twice.
+    DuplicateEntry { row: usize, column: usize },
+    /// The row pointers of a CSR matrix do not start at 0, decrease, or do not end at the
+    /// number of column indices.
+    InvalidIndptr,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::NoColumns => write!(f, "there is no column"),
+            ImportError::EmptyRow { row } => write!(f, "row {row} has no column"),
+            ImportError::RowLength { row, len } => {
+                write!(f, "row {row} has {len} entries instead of one per column")
+            }
+            ImportError::ColumnOutOfRange { row, column } => {
+                write!(f, "row {row} has column {column}, which does not exist")
+            }
+            ImportError::DuplicateEntry { row, column } => {
+                write!(f, "row {row} has column {column} twice")
+            }
+            ImportError::InvalidIndptr => write!(
+                f,
+                "indptr must start at 0, never decrease and end at the number of indices"
+            ),
+        }
+    }
+}
+
+impl Error for ImportError {}
+
+impl MatrixBuilder {
+    /// Creates a [`MatrixRowBuilder`] from a dense 0/1 matrix, given as one `Vec` of
+    /// entries per row.
+    ///
+    /// Each row must have one entry per column, and at least one `true` entry.
+    pub fn from_dense<T>(
+        columns: impl IntoIterator<Item = impl Into<ColumnSpec<T>>>,
+        rows: &[Vec<bool>],
+    ) -> Result<MatrixRowBuilder<T>, ImportError> {
+        let mut builder = column_builder(columns)?;
+
+        let cells = rows.iter().flatten().filter(|&&entry| entry).count();
+        builder.reserve(rows.len(), cells);
+
+        for (row, entries) in rows.iter().enumerate() {
+            if entries.len() != builder.column_count() {
+                return Err(ImportError::RowLength {
+                    row,
+                    len: entries.len(),
+                });
+            }
+
+            let indexes = entries.iter().enumerate().filter(|(_, entry)| **entry);
+            builder = add_row(builder, row, indexes.map(|(column, _)| column).collect())?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates a [`MatrixRowBuilder`] from a sparse matrix in the compressed sparse row
+    /// format: the columns of row `i` are `indices[indptr[i]..indptr[i + 1]]`.
+    ///
+    /// The columns of a row may be in any order, but must all be distinct.
+    pub fn from_csr<T>(
+        columns: impl IntoIterator<Item = impl Into<ColumnSpec<T>>>,
+        indptr: &[usize],
+        indices: &[usize],
+    ) -> Result<MatrixRowBuilder<T>, ImportError> {
+        if indptr.first() != Some(&0)
+            || indptr.last() != Some(&indices.len())
+            || indptr.windows(2).any(|pair| pair[0] > pair[1])
+        {
+            return Err(ImportError::InvalidIndptr);
+        }
+
+        let mut builder = column_builder(columns)?;
+        builder.reserve(indptr.len() - 1, indices.len());
+
+        for (row, range) in indptr.windows(2).enumerate() {
+            builder = add_row(builder, row, indices[range[0]..range[1]].to_vec())?;
+        }
+
+        Ok(builder)
+    }
+
+    /// Creates a [`MatrixRowBuilder`] from the `(row, column)` positions of the `true`
+    /// entries of a 0/1 matrix, in any order.
+    ///
+    /// The matrix has as many rows as the largest row index plus one, and each row must
+    /// have at least one entry.
+    pub fn from_pairs<T>(
+        columns: impl IntoIterator<Item = impl Into<ColumnSpec<T>>>,
+        pairs: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Result<MatrixRowBuilder<T>, ImportError> {
+        let mut builder = column_builder(columns)?;
+
+        let mut pairs = pairs.into_iter().collect_vec();
+        pairs.sort_unstable();
+
+        let rows = pairs.chunk_by(|a, b| a.0 == b.0).collect_vec();
+        builder.reserve(rows.len(), pairs.len());
+
+        // The pairs are sorted, so a missing row is found before any larger row index.
+        for (expected, entries) in rows.into_iter().enumerate() {
+            let row = entries[0].0;
+            if row != expected {
+                return Err(ImportError::EmptyRow { row: expected });
+            }
+
+            builder = add_row(builder, row, entries.iter().map(|&(_, c)| c).collect())?;
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Creates a builder with the given columns, checking that there is at least one.
+fn column_builder<T>(
+    columns: impl IntoIterator<Item = impl Into<ColumnSpec<T>>>,
+) -> Result<MatrixRowBuilder<T>, ImportError> {
+    let columns = columns.into_iter().map(Into::into).collect_vec();
+    if columns.is_empty() {
+        return Err(ImportError::NoColumns);
+    }
+
+    Ok(MatrixBuilder::from_iterable(columns))
+}
+
+/// Checks the column indexes of `row`, numbered from 0, and adds it to `builder`.
+fn add_row<T>(
+    builder: MatrixRowBuilder<T>,
+    row: usize,
+    mut indexes: Vec<usize>,
+) -> Result<MatrixRowBuilder<T>, ImportError> {
+    indexes.sort_unstable();
+
+    if indexes.is_empty() {
+        return Err(ImportError::EmptyRow { row });
+    }
+
+    if let Some(&column) = indexes.last()
+        && column >= builder.column_count()
+    {
+        return Err(ImportError::ColumnOutOfRange { row, column });
+    }
+
+    if let Some(pair) = indexes.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(ImportError::DuplicateEntry {
+            row,
+            column: pair[0],
+        });
+    }
+
+    Ok(builder.add_sorted_row_index(indexes.into_iter().map(|column| column + 1)))
+}
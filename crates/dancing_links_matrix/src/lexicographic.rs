@@ -0,0 +1,194 @@
+//! Enumeration of the exact covers in lexicographic order.
+//!
+//! The order of the solutions returned by [`IterativeAlgorithmXSolver`] depends on the
+//! column chosen at each step, so it changes with the heuristic. The solver here returns
+//! the solutions in a fixed order instead: sorted by their list of row indexes, in
+//! increasing order, compared lexicographically.
+//!
+//! [`IterativeAlgorithmXSolver`]: crate::IterativeAlgorithmXSolver
+
+use std::{collections::HashMap, ops::Range};
+
+use crate::{
+    DancingLinksMatrix,
+    column_set::ColumnSet,
+    matrix::CellIteratorDir,
+    solver::{Solution, cover_row, uncover_row},
+};
+
+/// A solver returning the exact covers in lexicographic order of their sorted row indexes.
+///
+/// The rows are considered by increasing index: each one is first included in the
+/// solution, then excluded. Like in Algorithm X, rows with only secondary columns are never
+/// included. A branch is abandoned as soon as a primary column has no row
+/// left.
+///
+/// Skipping solutions, with [`Self::solve_range`] or [`Self::nth`], counts the solutions
+/// of the skipped subtrees without enumerating them. The counts are memoized by the active
+/// columns and the first row that can still be chosen, so skipping uses memory
+/// proportional to the number of distinct subproblems.
+pub struct LexicographicSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+}
+
+impl<'a, T> LexicographicSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns all the solutions, in lexicographic order.
+    pub fn solve(&self) -> Vec<Solution<'a, T>> {
+        self.solve_range(0..usize::MAX)
+    }
+
+    /// Returns the solutions with index in `range`, the first solution having index 0.
+    pub fn solve_range(&self, range: Range<usize>) -> Vec<Solution<'a, T>> {
+        let mut walker = Walker {
+            matrix: &self.matrix,
+            memo: HashMap::new(),
+            skip: range.start as u128,
+            take: range.len(),
+            selection: Vec::new(),
+            found: Vec::new(),
+        };
+
+        if walker.take > 0 {
+            walker.walk(1);
+        }
+
+        walker
+            .found
+            .into_iter()
+            .map(|rows| self.matrix.solution_from_rows(rows))
+            .collect()
+    }
+
+    /// Returns the solution with the given index, or `None` if there are not enough
+    /// solutions.
+    pub fn nth(&self, index: usize) -> Option<Solution<'a, T>> {
+        self.solve_range(index..index.saturating_add(1)).pop()
+    }
+
+    /// Returns the number of solutions.
+    pub fn count(&self) -> u128 {
+        let mut walker = Walker {
+            matrix: &self.matrix,
+            memo: HashMap::new(),
+            skip: 0,
+            take: 0,
+            selection: Vec::new(),
+            found: Vec::new(),
+        };
+
+        walker.count(1)
+    }
+}
+
+struct Walker<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    /// The number of solutions of each subproblem, keyed by its active columns and the
+    /// first row that can be chosen.
+    memo: HashMap<(ColumnSet, usize), u128>,
+    /// The number of solutions still to skip.
+    skip: u128,
+    /// The number of solutions still to return.
+    take: usize,
+    /// The rows chosen so far.
+    selection: Vec<usize>,
+    found: Vec<Vec<usize>>,
+}
+
+impl<'a, T> Walker<'_, 'a, T> {
+    /// Calls `f` on each row with index `start` or more still in the matrix and with a
+    /// primary column, by increasing index, with the row chosen. Each row is hidden after its call, until all the rows
+    /// are done or `f` returns `false`.
+    fn branch(&mut self, start: usize, mut f: impl FnMut(&mut Self, usize) -> bool) {
+        let matrix = self.matrix;
+        let mut hidden = Vec::new();
+
+        for index in start..=matrix.row_count() {
+            if matrix.min_column().empty() {
+                break;
+            }
+
+            let row = matrix.row_cell(index).unwrap();
+            if !matrix.is_row_active(row) {
+                continue;
+            }
+
+            // The row is chosen through one of its primary columns, like Algorithm X does.
+            let Some(row) = matrix
+                .iterate_cells(row, CellIteratorDir::Right, true)
+                .find(|cell| cell.column().primary)
+            else {
+                continue;
+            };
+
+            matrix.cover(row.column());
+            cover_row(matrix, row);
+            let go_on = f(self, index);
+            uncover_row(matrix, row);
+            matrix.uncover(row.column());
+
+            if !go_on {
+                break;
+            }
+
+            matrix.hide_row(row);
+            hidden.push(row);
+        }
+
+        for row in hidden.into_iter().rev() {
+            matrix.unhide_row(row);
+        }
+    }
+
+    fn count(&mut self, start: usize) -> u128 {
+        if self.matrix.all_covered() {
+            return 1;
+        }
+
+        let key = (ColumnSet::active(self.matrix), start);
+        if let Some(&count) = self.memo.get(&key) {
+            return count;
+        }
+
+        let mut total = 0;
+        self.branch(start, |walker, index| {
+            total += walker.count(index + 1);
+            true
+        });
+
+        self.memo.insert(key, total);
+        total
+    }
+
+    fn walk(&mut self, start: usize) {
+        if self.matrix.all_covered() {
+            if self.skip > 0 {
+                self.skip -= 1;
+            } else {
+                self.found.push(self.selection.clone());
+                self.take -= 1;
+            }
+            return;
+        }
+
+        if self.skip > 0 {
+            let count = self.count(start);
+
+            if self.skip >= count {
+                self.skip -= count;
+                return;
+            }
+        }
+
+        self.branch(start, |walker, index| {
+            walker.selection.push(index);
+            walker.walk(index + 1);
+            walker.selection.pop();
+
+            walker.take > 0
+        });
+    }
+}
@@ -1,14 +1,63 @@
 mod arena;
+mod brute;
 mod builders;
+mod catalogue;
 mod cells;
+mod column_set;
+mod cost;
+mod counter;
+mod diverse;
+mod explorer;
+mod generator;
+mod import;
+mod lexicographic;
 mod matrix;
+mod nogood;
+mod partial;
+mod prune;
 mod queue;
+mod restarts;
+mod sampler;
+mod semiring;
+mod set_cover;
 mod solver;
+mod split;
+mod stats;
+mod stepper;
+mod stream;
+mod symmetry;
+mod verify;
+mod view;
+mod zdd;
 
 pub use arena::Arena;
+pub use brute::BruteForceSolver;
 pub use builders::{MatrixBuilder, MatrixColBuilder, MatrixRowBuilder};
+pub use catalogue::Benchmark;
+pub use cost::{CostSolution, MinCostSolver};
+pub use counter::{CountStats, MemoCounter, WeightedCounter};
+pub use diverse::DiverseSolver;
+pub use explorer::{ChooseError, Explorer};
+pub use generator::{GeneratedInstance, InstanceGenerator};
+pub use import::ImportError;
+pub use lexicographic::LexicographicSolver;
 pub use matrix::{ColumnSpec, DancingLinksMatrix};
-pub use solver::{IterativeAlgorithmXSolver, Solution};
+pub use nogood::{NogoodSolver, NogoodStats};
+pub use partial::{MaxPartialCoverSolver, PartialCover};
+pub use prune::{PartialSelection, Pruner};
+pub use restarts::RestartStrategy;
+pub use sampler::RandomSampler;
+pub use semiring::{Polynomial, Semiring};
+pub use set_cover::SetCoverSolver;
+pub use solver::{IterativeAlgorithmXSolver, SearchEvent, Solution};
+pub use split::{PartialResult, SubInstance};
+pub use stats::{Diagnostic, Distribution, MatrixStats};
+pub use stepper::Stepper;
+pub use stream::StreamingBuilder;
+pub use symmetry::{Orbit, Symmetry, SymmetryError, SymmetryGroup};
+pub use verify::{Verification, verify, verify_items};
+pub use view::{ColumnView, RowView};
+pub use zdd::{Zdd, ZddNode, ZddRef};
 
 #[cfg(test)]
 mod tests;
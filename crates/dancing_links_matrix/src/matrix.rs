@@ -1,4 +1,5 @@
 use std::{
+    cell::RefCell,
     collections::{HashMap, HashSet},
     fmt,
     hash::Hash,
@@ -6,11 +7,15 @@ use std::{
 };
 
 use itertools::Itertools;
-use rand::{Rng, thread_rng};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
 
 use crate::{
+    Explorer,
     cells::{CellRow, ColumnRef, MatrixCell, MatrixCellRef},
     queue::ColumnPriorityQueue,
+    solver::Solution,
+    stats::{self, Diagnostic, MatrixStats},
+    view::{ColumnView, RowView},
 };
 
 pub struct ColumnSpec<T> {
@@ -46,6 +51,20 @@ pub struct DancingLinksMatrix<'a, T> {
     pub(crate) columns: Box<[ColumnRef<'a, T>]>,
     pub(crate) cells: Box<[MatrixCellRef<'a, T>]>,
     pub(crate) columns_queue: ColumnPriorityQueue<'a, T>,
+    pub(crate) row_weights: Box<[f64]>,
+    /// The random number generator used for the randomized choices of the solvers.
+    pub(crate) rng: RefCell<StdRng>,
+}
+
+/// How the solvers choose the column to branch on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColumnChoice {
+    /// The column with the fewest rows, ties broken by column order.
+    Min,
+    /// The column with the fewest rows, ties broken at random.
+    MinRandomTies,
+    /// A random column.
+    Random,
 }
 
 impl<'a, T> DancingLinksMatrix<'a, T> {
@@ -69,12 +88,140 @@ impl<'a, T> DancingLinksMatrix<'a, T> {
             })
     }
 
+    /// Returns the number of rows of the matrix.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns the number of columns of the matrix, both primary and secondary.
+    pub fn column_count(&self) -> usize {
+        self.column_count
+    }
+
+    /// Returns views of the rows, by increasing index.
+    pub fn rows(&self) -> impl Iterator<Item = RowView<'_, 'a, T>> {
+        (1..=self.row_count).map(|row| RowView::new(self, row).unwrap())
+    }
+
+    /// Returns a view of the row with index `row`, or `None` if there is no such row.
+    ///
+    /// Rows are numbered from 1, in the order they were added to the builder.
+    pub fn row(&self, row: usize) -> Option<RowView<'_, 'a, T>> {
+        RowView::new(self, row)
+    }
+
+    /// Returns views of the columns, primary and secondary, in the order they were added.
+    pub fn columns(&self) -> impl Iterator<Item = ColumnView<'_, 'a, T>> {
+        (1..=self.column_count).map(|column| ColumnView::new(self, column).unwrap())
+    }
+
+    /// Returns a view of the column with index `column`, or `None` if there is no such
+    /// column.
+    ///
+    /// Columns are numbered from 1, in the order they were added to the builder.
+    pub fn column(&self, column: usize) -> Option<ColumnView<'_, 'a, T>> {
+        ColumnView::new(self, column)
+    }
+
+    /// Returns the weight of the row with index `row`.
+    ///
+    /// Rows are numbered from 1, in the order they were added to the builder.
+    /// Rows without an explicit weight weigh `1.0`.
+    pub fn row_weight(&self, row: usize) -> f64 {
+        self.row_weights[row - 1]
+    }
+
+    /// Returns the first cell of the row with index `row`, or `None` if there is no such row.
+    pub(crate) fn row_cell(&self, row: usize) -> Option<MatrixCellRef<'a, T>> {
+        if row == 0 || row > self.row_count {
+            return None;
+        }
+
+        let position = self.cells.partition_point(|cell| match cell.row {
+            CellRow::Header => true,
+            CellRow::Data(r) => usize::from(r) < row,
+        });
+
+        Some(self.cells[position])
+    }
+
+    /// Creates a [`Solution`] from the indexes of its rows.
+    ///
+    /// Rows are numbered from 1, in the order they were added to the builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row index is not valid.
+    ///
+    /// [`Solution`]: crate::Solution
+    pub fn solution_from_rows(&self, rows: impl IntoIterator<Item = usize>) -> Solution<'a, T> {
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                self.row_cell(row)
+                    .unwrap_or_else(|| panic!("Row {row} not found"))
+            })
+            .collect_vec();
+
+        Solution::from_rows(self, &rows)
+    }
+
+    /// Returns the number of primary columns of each row, indexed by row index - 1.
+    pub(crate) fn primary_row_lengths(&self) -> Vec<usize> {
+        let mut lengths = vec![0; self.row_count];
+
+        for cell in &self.cells {
+            if let CellRow::Data(row) = cell.row
+                && cell.column().primary
+            {
+                lengths[usize::from(row) - 1] += 1;
+            }
+        }
+
+        lengths
+    }
+
+    /// Returns the number of primary columns not yet covered.
+    pub(crate) fn uncovered_count(&self) -> usize {
+        self.columns_queue.len()
+    }
+
+    /// Returns `true` if none of the columns of `row` is covered, so that it can be chosen.
+    pub(crate) fn is_row_active(&self, row: MatrixCellRef<'a, T>) -> bool {
+        self.iterate_cells(row, CellIteratorDir::Right, true)
+            .all(|cell| !cell.column().covered())
+    }
+
+    /// Returns an [`Explorer`] to choose and unchoose rows by hand.
+    ///
+    /// The matrix is borrowed mutably, since the explorer modifies it until it is dropped.
+    pub fn explorer(&mut self) -> Explorer<'_, 'a, T> {
+        Explorer::new(self)
+    }
+
+    /// Returns statistics about the matrix, with the problems found by [`Self::diagnose`].
+    pub fn stats(&self) -> MatrixStats<'_, T> {
+        MatrixStats::new(self)
+    }
+
+    /// Returns the problems of the matrix: empty primary columns, unused secondary columns
+    /// and duplicate rows.
+    pub fn diagnose(&self) -> Vec<Diagnostic<'_, T>> {
+        stats::diagnose(self)
+    }
+
+    /// Returns `true` if all the primary columns are covered.
+    pub(crate) fn all_covered(&self) -> bool {
+        let column_cell = self.first_column().cell();
+        ptr::eq(column_cell.right(), column_cell)
+    }
+
     pub(crate) fn min_column(&self) -> ColumnRef<'a, T> {
         self.columns_queue.peek().unwrap()
     }
 
     pub(crate) fn random_column(&self) -> ColumnRef<'a, T> {
-        let num = thread_rng().gen_range(0..self.column_count);
+        let num = self.rng.borrow_mut().gen_range(0..self.uncovered_count());
 
         let start = self.first_column();
 
@@ -83,11 +230,65 @@ impl<'a, T> DancingLinksMatrix<'a, T> {
         iter.nth(num).unwrap()
     }
 
+    /// Returns a random column among the ones with the fewest rows.
+    pub(crate) fn random_min_column(&self) -> ColumnRef<'a, T> {
+        let size = self.min_column().size();
+
+        let candidates = self
+            .iterate_columns(self.first_column(), ColumnIteratorDir::Right, false)
+            .filter(|column| column.size() == size)
+            .collect_vec();
+
+        candidates.choose(&mut *self.rng.borrow_mut()).unwrap()
+    }
+
+    /// Returns the column to branch on, chosen according to `choice`.
+    pub(crate) fn choose_column(&self, choice: ColumnChoice) -> ColumnRef<'a, T> {
+        match choice {
+            ColumnChoice::Min => self.min_column(),
+            ColumnChoice::MinRandomTies => self.random_min_column(),
+            ColumnChoice::Random => self.random_column(),
+        }
+    }
+
+    /// Reseeds the random number generator used for the randomized choices.
+    pub(crate) fn reseed(&self, seed: u64) {
+        *self.rng.borrow_mut() = StdRng::seed_from_u64(seed);
+    }
+
+    /// Shuffles the order of the rows in each column.
+    ///
+    /// The rows of a column are tried by the solvers in the order they appear in the column,
+    /// which by default is the order they were added to the builder.
+    ///
+    /// Must be called when no column is covered.
+    pub(crate) fn shuffle_rows(&self) {
+        let rng = &mut *self.rng.borrow_mut();
+
+        for &column in self.columns.iter().skip(1) {
+            debug_assert!(!column.covered(), "Cannot shuffle a covered column");
+
+            let header = column.cell();
+            let mut rows = self
+                .iterate_cells(header, CellIteratorDir::Down, false)
+                .collect_vec();
+            rows.shuffle(rng);
+
+            let mut last = header;
+            for row in rows {
+                last.link_down(row);
+                last = row;
+            }
+            last.link_down(header);
+        }
+    }
+
     pub(crate) fn cover(&self, column: ColumnRef<'a, T>) {
         let pq = &self.columns_queue;
 
         let hc = column.cell();
         hc.skip_horizontal();
+        column.set_covered(true);
 
         pq.remove(column);
 
@@ -114,12 +315,57 @@ impl<'a, T> DancingLinksMatrix<'a, T> {
         }
 
         hc.restore_horizontal();
+        column.set_covered(false);
 
         if column.primary {
             pq.push(column);
         }
     }
 
+    /// Covers the header of a column, without removing its rows from the matrix.
+    ///
+    /// The rows intersecting the column can still be chosen, but the column will not be
+    /// chosen for branching anymore.
+    pub(crate) fn cover_header(&self, column: ColumnRef<'a, T>) {
+        column.cell().skip_horizontal();
+        column.set_covered(true);
+        self.columns_queue.remove(column);
+    }
+
+    /// Restores the header of a column covered by [`Self::cover_header`].
+    pub(crate) fn uncover_header(&self, column: ColumnRef<'a, T>) {
+        column.cell().restore_horizontal();
+        column.set_covered(false);
+
+        if column.primary {
+            self.columns_queue.push(column);
+        }
+    }
+
+    /// Removes a row from the matrix, so that it cannot be chosen anymore.
+    pub(crate) fn hide_row(&self, row: MatrixCellRef<'a, T>) {
+        let pq = &self.columns_queue;
+
+        for j in self.iterate_cells(row, CellIteratorDir::Right, true) {
+            j.skip_vertical();
+            j.column().decrease_size();
+            pq.change_priority(j.column());
+        }
+    }
+
+    /// Restores a row removed by [`Self::hide_row`].
+    ///
+    /// Rows must be restored in the reverse order they were hidden.
+    pub(crate) fn unhide_row(&self, row: MatrixCellRef<'a, T>) {
+        let pq = &self.columns_queue;
+
+        for j in self.iterate_cells(row, CellIteratorDir::Left, true) {
+            j.restore_vertical();
+            j.column().increase_size();
+            pq.change_priority(j.column());
+        }
+    }
+
     pub(crate) fn iterate_cells(
         &self,
         start: MatrixCellRef<'a, T>,
@@ -0,0 +1,306 @@
+//! Algorithm X with nogood learning.
+//!
+//! When a primary column has no row left, the rows chosen earlier that removed its rows
+//! explain the failure: any selection containing them fails the same way. Such a set of
+//! rows is a nogood. When every branch of a column fails, the nogoods of the branches,
+//! without the rows of the column, form a nogood for the parent node. The nogoods learned
+//! are stored and checked each time a row is chosen, pruning the branches containing one,
+//! and a branch whose nogood does not contain its own row backjumps directly to the node
+//! where the nogood was completed.
+
+use std::{collections::HashSet, fmt};
+
+use itertools::Itertools;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::CellIteratorDir,
+    solver::{Solution, cover_row, uncover_row},
+};
+
+/// Statistics about the nogoods of a search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NogoodStats {
+    /// The number of rows chosen during the search.
+    pub nodes: usize,
+    /// The number of times a primary column had no row left.
+    pub conflicts: usize,
+    /// The number of nogoods stored.
+    pub learned: usize,
+    /// The number of nogoods not stored because the store was full.
+    pub discarded: usize,
+    /// The memory used by the nogoods stored, in bytes.
+    pub bytes: usize,
+    /// The number of branches pruned because they contained a stored nogood.
+    pub pruned: usize,
+    /// The number of nodes left early because a nogood did not contain their row.
+    pub backjumps: usize,
+}
+
+impl fmt::Display for NogoodStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} nodes, {} conflicts, {} nogoods learned ({:.1} KiB), {} discarded, {} pruned, \
+             {} backjumps",
+            self.nodes,
+            self.conflicts,
+            self.learned,
+            self.bytes as f64 / 1024.0,
+            self.discarded,
+            self.pruned,
+            self.backjumps
+        )
+    }
+}
+
+/// A solver running Algorithm X with nogood learning.
+pub struct NogoodSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    return_first: bool,
+    memory_limit: usize,
+    /// The columns of each row, indexed by row index - 1.
+    row_columns: Vec<Vec<usize>>,
+    /// The rows of each column, indexed by column index.
+    column_rows: Vec<Vec<usize>>,
+}
+
+impl<'a, T> NogoodSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>, return_first: bool) -> Self {
+        let row_columns = (1..=matrix.row_count())
+            .map(|row| {
+                matrix
+                    .iterate_cells(matrix.row_cell(row).unwrap(), CellIteratorDir::Right, true)
+                    .map(|cell| cell.column().index)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let mut column_rows = vec![Vec::new(); matrix.column_count() + 1];
+        for (i, columns) in row_columns.iter().enumerate() {
+            for &column in columns {
+                column_rows[column].push(i + 1);
+            }
+        }
+
+        Self {
+            matrix,
+            return_first,
+            memory_limit: 64 << 20,
+            row_columns,
+            column_rows,
+        }
+    }
+
+    /// Limits the memory used by the nogoods to about `bytes` bytes, the nogoods learned
+    /// once the limit is reached are discarded.
+    ///
+    /// Each nogood takes memory proportional to its number of rows. Defaults to 64 MiB.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = bytes;
+        self
+    }
+
+    pub fn solve(&self) -> Vec<Solution<'a, T>> {
+        self.solve_with_stats().0
+    }
+
+    /// Returns the solutions, together with statistics about the nogoods.
+    pub fn solve_with_stats(&self) -> (Vec<Solution<'a, T>>, NogoodStats) {
+        let rows = self.matrix.row_count();
+
+        let mut learner = Learner {
+            solver: self,
+            selection: Vec::new(),
+            position: vec![None; rows + 1],
+            owner: vec![None; self.matrix.column_count() + 1],
+            nogoods: Vec::new(),
+            watches: vec![Vec::new(); rows + 1],
+            solutions: Vec::new(),
+            stats: NogoodStats::default(),
+        };
+
+        learner.explore();
+
+        (learner.solutions, learner.stats)
+    }
+}
+
+/// The outcome of the search of a subtree.
+enum Outcome {
+    /// The subtree has at least one solution.
+    Solved,
+    /// The subtree has no solution, because the selection contains the nogood.
+    Failed(Vec<usize>),
+    /// The search must stop.
+    Stopped,
+}
+
+struct Learner<'s, 'a, T> {
+    solver: &'s NogoodSolver<'a, T>,
+    selection: Vec<MatrixCellRef<'a, T>>,
+    /// The position in the selection of each chosen row, indexed by row index.
+    position: Vec<Option<usize>>,
+    /// The chosen row covering each column, indexed by column index.
+    owner: Vec<Option<usize>>,
+    /// The nogoods learned, as sorted lists of row indexes.
+    nogoods: Vec<Vec<usize>>,
+    /// The nogoods containing each row, indexed by row index.
+    watches: Vec<Vec<usize>>,
+    solutions: Vec<Solution<'a, T>>,
+    stats: NogoodStats,
+}
+
+impl<'a, T> Learner<'_, 'a, T> {
+    fn explore(&mut self) -> Outcome {
+        let solver = self.solver;
+        let matrix = &solver.matrix;
+
+        if matrix.all_covered() {
+            self.solutions
+                .push(Solution::from_rows(matrix, &self.selection));
+
+            return match self.solver.return_first {
+                true => Outcome::Stopped,
+                false => Outcome::Solved,
+            };
+        }
+
+        let column = matrix.min_column();
+
+        if column.empty() {
+            self.stats.conflicts += 1;
+
+            let nogood = self.solver.column_rows[column.index]
+                .iter()
+                .map(|&row| self.reason(row))
+                .sorted()
+                .dedup()
+                .collect_vec();
+
+            self.learn(&nogood);
+            return Outcome::Failed(nogood);
+        }
+
+        // The rows of the column removed by the chosen rows are part of the explanation.
+        let active = matrix
+            .iterate_cells(column.cell(), CellIteratorDir::Down, false)
+            .map(|cell| usize::from(cell.row))
+            .collect::<HashSet<_>>();
+
+        let mut nogood = self.solver.column_rows[column.index]
+            .iter()
+            .filter(|row| !active.contains(row))
+            .map(|&row| self.reason(row))
+            .collect_vec();
+
+        let mut solved = false;
+
+        matrix.cover(column);
+
+        for row in matrix.iterate_cells(column.cell(), CellIteratorDir::Down, false) {
+            let index = usize::from(row.row);
+
+            self.choose(row);
+            let outcome = match self.violated(index) {
+                Some(nogood) => {
+                    self.stats.pruned += 1;
+                    Outcome::Failed(nogood)
+                }
+                None => self.explore(),
+            };
+            self.unchoose(row);
+
+            match outcome {
+                Outcome::Solved => solved = true,
+                Outcome::Stopped => {
+                    matrix.uncover(column);
+                    return Outcome::Stopped;
+                }
+                Outcome::Failed(failed) if !failed.contains(&index) => {
+                    self.stats.backjumps += 1;
+                    matrix.uncover(column);
+                    return Outcome::Failed(failed);
+                }
+                Outcome::Failed(failed) => {
+                    nogood.extend(failed.into_iter().filter(|&r| r != index));
+                }
+            }
+        }
+
+        matrix.uncover(column);
+
+        if solved {
+            return Outcome::Solved;
+        }
+
+        let nogood = nogood.into_iter().sorted().dedup().collect_vec();
+        self.learn(&nogood);
+        Outcome::Failed(nogood)
+    }
+
+    fn choose(&mut self, row: MatrixCellRef<'a, T>) {
+        let index = usize::from(row.row);
+
+        self.stats.nodes += 1;
+        cover_row(&self.solver.matrix, row);
+
+        self.position[index] = Some(self.selection.len());
+        for &column in &self.solver.row_columns[index - 1] {
+            self.owner[column] = Some(index);
+        }
+        self.selection.push(row);
+    }
+
+    fn unchoose(&mut self, row: MatrixCellRef<'a, T>) {
+        let index = usize::from(row.row);
+
+        self.selection.pop();
+        for &column in &self.solver.row_columns[index - 1] {
+            self.owner[column] = None;
+        }
+        self.position[index] = None;
+
+        uncover_row(&self.solver.matrix, row);
+    }
+
+    /// Returns the earliest chosen row sharing a column with `row`.
+    fn reason(&self, row: usize) -> usize {
+        self.solver.row_columns[row - 1]
+            .iter()
+            .filter_map(|&column| self.owner[column])
+            .min_by_key(|&owner| self.position[owner])
+            .expect("a row removed from the matrix conflicts with a chosen row")
+    }
+
+    /// Returns a stored nogood containing `row` whose rows are all chosen.
+    fn violated(&self, row: usize) -> Option<Vec<usize>> {
+        self.watches[row]
+            .iter()
+            .map(|&i| &self.nogoods[i])
+            .find(|nogood| nogood.iter().all(|&r| self.position[r].is_some()))
+            .cloned()
+    }
+
+    fn learn(&mut self, nogood: &[usize]) {
+        // The rows of the nogood are stored once in the nogood and once in the watches.
+        let size = size_of::<Vec<usize>>() + 2 * nogood.len() * size_of::<usize>();
+
+        if self.stats.bytes + size > self.solver.memory_limit {
+            self.stats.discarded += 1;
+            return;
+        }
+
+        self.stats.bytes += size;
+
+        for &row in nogood {
+            self.watches[row].push(self.nogoods.len());
+        }
+
+        self.nogoods.push(nogood.to_vec());
+        self.stats.learned += 1;
+    }
+}
@@ -0,0 +1,176 @@
+//! Maximum partial cover.
+//!
+//! When a matrix has no exact cover, the best alternative is often a set of disjoint rows
+//! covering as many primary columns as possible.
+
+use crate::{
+    DancingLinksMatrix,
+    cells::{ColumnName, ColumnRef, MatrixCellRef},
+    matrix::{CellIteratorDir, ColumnIteratorDir},
+    solver::{Solution, cover_row, uncover_row},
+};
+
+/// A set of pairwise disjoint rows, with the primary columns it leaves uncovered.
+pub struct PartialCover<'a, T> {
+    /// The chosen rows.
+    pub solution: Solution<'a, T>,
+    /// The total weight of the primary columns covered by the rows.
+    pub covered_weight: f64,
+    /// The primary columns not covered by any row.
+    pub uncovered: Vec<&'a T>,
+}
+
+impl<T> PartialCover<'_, T> {
+    /// Returns `true` if the cover is an exact cover.
+    pub fn is_exact(&self) -> bool {
+        self.uncovered.is_empty()
+    }
+}
+
+/// A solver looking for the set of pairwise disjoint rows covering the maximum number, or
+/// the maximum total weight, of primary columns.
+///
+/// For each primary column, the search tries every row of the column and then leaves the
+/// column uncovered, removing all the rows intersecting it. Branches that cannot cover more
+/// than the best cover found so far, even covering all the remaining columns, are pruned.
+pub struct MaxPartialCoverSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+}
+
+impl<'a, T> MaxPartialCoverSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns a set of disjoint rows covering the maximum number of primary columns.
+    pub fn solve(&self) -> PartialCover<'a, T> {
+        self.solve_weighted(|_| 1.0)
+    }
+
+    /// Returns a set of disjoint rows covering primary columns with the maximum total weight.
+    ///
+    /// `column_weight` returns the weight of a primary column given its name, and must be
+    /// non negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is negative, infinite or NaN.
+    pub fn solve_weighted(&self, column_weight: impl Fn(&T) -> f64) -> PartialCover<'a, T> {
+        let weights = self
+            .matrix
+            .columns
+            .iter()
+            .map(|column| match &column.name {
+                ColumnName::Other(name) if column.primary => {
+                    let weight = column_weight(name);
+                    assert!(
+                        weight.is_finite() && weight >= 0.0,
+                        "Column weight must be finite and non negative, got {weight}"
+                    );
+
+                    weight
+                }
+                _ => 0.0,
+            })
+            .collect();
+
+        let mut explorer = Explorer {
+            matrix: &self.matrix,
+            weights,
+            chosen: Vec::new(),
+            uncovered: Vec::new(),
+            best: None,
+        };
+
+        explorer.explore(0.0);
+
+        let (chosen, uncovered, covered_weight) = explorer.best.unwrap();
+
+        PartialCover {
+            solution: Solution::from_rows(&self.matrix, &chosen),
+            covered_weight,
+            uncovered: uncovered
+                .into_iter()
+                .filter_map(|c| c.name.get_name())
+                .collect(),
+        }
+    }
+}
+
+type Best<'a, T> = (Vec<MatrixCellRef<'a, T>>, Vec<ColumnRef<'a, T>>, f64);
+
+struct Explorer<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    /// The weight of each column, indexed by column index.
+    weights: Vec<f64>,
+    chosen: Vec<MatrixCellRef<'a, T>>,
+    uncovered: Vec<ColumnRef<'a, T>>,
+    best: Option<Best<'a, T>>,
+}
+
+impl<'a, T> Explorer<'_, 'a, T> {
+    /// Explores the covers extending the current one, returning `true` if the search can
+    /// stop because an exact cover has been found.
+    fn explore(&mut self, covered: f64) -> bool {
+        let matrix = self.matrix;
+
+        if matrix.all_covered() {
+            if self.best.as_ref().is_none_or(|(_, _, w)| covered > *w) {
+                self.best = Some((self.chosen.clone(), self.uncovered.clone(), covered));
+            }
+
+            return self.uncovered.is_empty();
+        }
+
+        if let Some((_, _, best)) = &self.best
+            && covered + self.remaining_weight() <= *best
+        {
+            return false;
+        }
+
+        let column = matrix.min_column();
+        matrix.cover(column);
+
+        let mut done = false;
+
+        for row in matrix.iterate_cells(column.cell(), CellIteratorDir::Down, false) {
+            cover_row(matrix, row);
+            self.chosen.push(row);
+
+            done = self.explore(covered + self.row_weight(row));
+
+            self.chosen.pop();
+            uncover_row(matrix, row);
+
+            if done {
+                break;
+            }
+        }
+
+        if !done {
+            self.uncovered.push(column);
+            done = self.explore(covered);
+            self.uncovered.pop();
+        }
+
+        matrix.uncover(column);
+
+        done
+    }
+
+    /// Returns the total weight of the primary columns not yet covered.
+    fn remaining_weight(&self) -> f64 {
+        self.matrix
+            .iterate_columns(self.matrix.first_column(), ColumnIteratorDir::Right, false)
+            .map(|column| self.weights[column.index])
+            .sum()
+    }
+
+    /// Returns the total weight of the primary columns of a row.
+    fn row_weight(&self, row: MatrixCellRef<'a, T>) -> f64 {
+        self.matrix
+            .iterate_cells(row, CellIteratorDir::Right, true)
+            .map(|cell| self.weights[cell.column().index])
+            .sum()
+    }
+}
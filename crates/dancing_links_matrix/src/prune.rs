@@ -0,0 +1,111 @@
+//! User defined pruning, for constraints that are not exact cover constraints.
+
+use std::cell::RefCell;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::CellIteratorDir,
+    solver::{SearchFlow, SearchHooks},
+};
+
+/// A view of the rows chosen so far, given to a [`Pruner`].
+pub struct PartialSelection<'s, 'a, T> {
+    matrix: &'s DancingLinksMatrix<'a, T>,
+    rows: &'s [MatrixCellRef<'a, T>],
+}
+
+impl<'s, 'a, T> PartialSelection<'s, 'a, T> {
+    /// Returns the number of rows chosen.
+    pub fn depth(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the indexes of the rows chosen, numbered from 1, in the order they were
+    /// chosen.
+    pub fn rows(&self) -> impl Iterator<Item = usize> + use<'s, 'a, T> {
+        self.rows.iter().map(|row| row.row.into())
+    }
+
+    /// Returns the columns of the `position`-th row chosen, starting from 0.
+    pub fn row_items(&self, position: usize) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        self.matrix
+            .iterate_cells(self.rows[position], CellIteratorDir::Right, true)
+            .filter_map(|cell| cell.name().get_name())
+    }
+
+    /// Returns the columns of the last row chosen.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no row was chosen yet.
+    pub fn last_row_items(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        self.row_items(self.rows.len() - 1)
+    }
+
+    /// Returns the matrix, with the columns of the chosen rows covered.
+    pub fn matrix(&self) -> &DancingLinksMatrix<'a, T> {
+        self.matrix
+    }
+}
+
+/// A side constraint checked by the solver after each row choice.
+///
+/// Closures taking a [`PartialSelection`] and returning a `bool` are pruners.
+pub trait Pruner<T> {
+    /// Called after the last row of `selection` has been chosen.
+    ///
+    /// Returns `false` to prune the branch: no solution containing the rows of `selection`
+    /// is returned.
+    fn accept(&mut self, selection: &PartialSelection<'_, '_, T>) -> bool;
+}
+
+impl<T, F> Pruner<T> for F
+where
+    F: FnMut(&PartialSelection<'_, '_, T>) -> bool,
+{
+    fn accept(&mut self, selection: &PartialSelection<'_, '_, T>) -> bool {
+        self(selection)
+    }
+}
+
+/// A pruner stored by a solver, mutable through a shared reference.
+pub(crate) type SharedPruner<'a, T> = RefCell<Box<dyn Pruner<T> + 'a>>;
+
+/// Hooks asking `pruner` before accepting a branch of `inner`.
+pub(crate) struct Pruned<'p, 'a, T, H> {
+    pub(crate) pruner: Option<&'p SharedPruner<'a, T>>,
+    pub(crate) inner: H,
+}
+
+impl<'a, T, H: SearchHooks<'a, T>> SearchHooks<'a, T> for Pruned<'_, 'a, T, H> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        if !self.inner.accept(matrix, selection) {
+            return false;
+        }
+
+        match self.pruner {
+            Some(pruner) => pruner.borrow_mut().accept(&PartialSelection {
+                matrix,
+                rows: selection,
+            }),
+            None => true,
+        }
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        self.inner.solution(matrix, selection)
+    }
+
+    fn stopped(&self) -> bool {
+        self.inner.stopped()
+    }
+}
@@ -33,6 +33,10 @@ impl<'a, T> ColumnPriorityQueue<'a, T> {
             .change_priority(&column, column_priority(column));
     }
 
+    pub(crate) fn len(&self) -> usize {
+        self.queue.borrow().len()
+    }
+
     pub(crate) fn peek(&self) -> Option<ColumnRef<'a, T>> {
         self.queue.borrow().peek().map(|(column, _)| *column)
     }
@@ -0,0 +1,66 @@
+//! Restart strategies for randomized searches.
+//!
+//! The running time of a randomized backtracking search is often heavy-tailed: a few
+//! unlucky choices near the root can make the search last much longer than usual.
+//! Restarting the search with fresh random choices after a limited number of nodes avoids
+//! getting stuck in those cases.
+
+/// The sequence of node limits used for the restarts of a search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestartStrategy {
+    /// Luby's universal sequence `1, 1, 2, 1, 1, 2, 4, 1, 1, 2, ...`, multiplied by `unit`.
+    Luby { unit: usize },
+    /// A sequence starting from `initial` and multiplied by `factor` after each restart.
+    ///
+    /// `factor` must be greater than 1, so that the search eventually terminates.
+    Geometric { initial: usize, factor: f64 },
+}
+
+impl RestartStrategy {
+    /// Checks that the node limits of the strategy grow, so that a search restarted
+    /// according to it eventually terminates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy is geometric and `factor` is not finite and greater than 1.
+    pub(crate) fn validate(&self) {
+        if let RestartStrategy::Geometric { factor, .. } = *self {
+            assert!(
+                factor.is_finite() && factor > 1.0,
+                "Restart factor must be finite and greater than 1, got {factor}"
+            );
+        }
+    }
+
+    /// Returns the maximum number of nodes explored by the attempt with index `attempt`,
+    /// starting from 0.
+    pub fn node_limit(&self, attempt: u32) -> usize {
+        match *self {
+            RestartStrategy::Luby { unit } => unit.max(1).saturating_mul(luby(attempt as u64 + 1)),
+            RestartStrategy::Geometric { initial, factor } => {
+                let limit = initial.max(1) as f64 * factor.powi(attempt as i32);
+                if limit >= usize::MAX as f64 {
+                    usize::MAX
+                } else {
+                    limit as usize
+                }
+            }
+        }
+    }
+}
+
+/// Returns the `i`-th element of Luby's sequence, starting from 1.
+fn luby(mut i: u64) -> usize {
+    loop {
+        let mut k = 1;
+        while (1 << k) - 1 < i {
+            k += 1;
+        }
+
+        if i == (1 << k) - 1 {
+            return 1 << (k - 1);
+        }
+
+        i -= (1 << (k - 1)) - 1;
+    }
+}
@@ -0,0 +1,103 @@
+//! Random sampling of exact covers.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    DancingLinksMatrix, RestartStrategy, Solution, Zdd,
+    matrix::ColumnChoice,
+    solver::{Collector, RowBounds, SearchHooks, search},
+};
+
+/// A sampler returning random exact covers of a matrix.
+///
+/// The sampler is seeded, so that the same seed always returns the same sequence of
+/// solutions.
+///
+/// [`RandomSampler::sample`] shuffles the order of the rows in each column and runs
+/// Algorithm X, breaking ties between columns at random, until the first solution. The
+/// search is restarted with a new order when it explores more nodes than allowed by the
+/// [`RestartStrategy`], to avoid getting stuck in large subtrees without solutions. The
+/// solutions are only roughly uniform.
+///
+/// [`RandomSampler::sample_uniform`] returns solutions chosen exactly uniformly, but needs
+/// to build the [`Zdd`] of all the solutions, so it only works on small enough instances.
+pub struct RandomSampler<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    rng: StdRng,
+    restarts: RestartStrategy,
+    bounds: RowBounds,
+    zdd: Option<Zdd>,
+}
+
+impl<'a, T> RandomSampler<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>, seed: u64) -> Self {
+        Self {
+            bounds: RowBounds::unbounded(&matrix),
+            matrix,
+            rng: StdRng::seed_from_u64(seed),
+            restarts: RestartStrategy::Geometric {
+                initial: usize::MAX,
+                factor: 2.0,
+            },
+            zdd: None,
+        }
+    }
+
+    /// Restarts the search when it explores more than `limit` nodes without finding a
+    /// solution.
+    ///
+    /// The limit doubles after each restart, so that the sampler eventually terminates
+    /// even when the instance has no solution.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_node_limit(self, limit: usize) -> Self {
+        self.with_restarts(RestartStrategy::Geometric {
+            initial: limit,
+            factor: 2.0,
+        })
+    }
+
+    /// Restarts the search according to `strategy`.
+    ///
+    /// Returns `self`, for chaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy is geometric and its factor is not finite and greater than 1.
+    pub fn with_restarts(mut self, strategy: RestartStrategy) -> Self {
+        strategy.validate();
+        self.restarts = strategy;
+        self
+    }
+
+    /// Returns a random exact cover, or `None` if there is none.
+    pub fn sample(&mut self) -> Option<Solution<'a, T>> {
+        let mut attempt = 0;
+
+        loop {
+            let limit = self.restarts.node_limit(attempt);
+
+            self.matrix.reseed(self.rng.r#gen());
+            self.matrix.shuffle_rows();
+
+            let mut collector = Collector::new(true, self.bounds, limit);
+            search(&self.matrix, ColumnChoice::MinRandomTies, &mut collector);
+
+            if !collector.stopped() {
+                return collector.solutions.pop();
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Returns an exact cover chosen uniformly at random, or `None` if there is none.
+    ///
+    /// The [`Zdd`] of all the solutions is built on the first call, and reused afterwards.
+    pub fn sample_uniform(&mut self) -> Option<Solution<'a, T>> {
+        let zdd = self.zdd.get_or_insert_with(|| Zdd::build(&self.matrix));
+
+        zdd.sample(&mut self.rng)
+            .map(|rows| self.matrix.solution_from_rows(rows))
+    }
+}
@@ -0,0 +1,133 @@
+//! Semirings used to aggregate weights over the solutions.
+
+/// A commutative semiring.
+///
+/// The weight of a solution is the product of the weights of its rows, and the weights
+/// of the solutions are summed.
+pub trait Semiring: Clone {
+    /// The neutral element of [`Self::add`], the weight of an empty set of solutions.
+    fn zero() -> Self;
+
+    /// The neutral element of [`Self::mul`], the weight of an empty set of rows.
+    fn one() -> Self;
+
+    fn add(&self, other: &Self) -> Self;
+
+    fn mul(&self, other: &Self) -> Self;
+
+    /// Returns the memory owned by the value on the heap, in bytes, counted by the memory
+    /// limit of the [`WeightedCounter`] cache.
+    ///
+    /// [`WeightedCounter`]: crate::WeightedCounter
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+/// Counting: every row has weight 1.
+impl Semiring for u128 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn one() -> Self {
+        1
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+/// Real weights, like probabilities or Boltzmann factors.
+impl Semiring for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+/// A polynomial in one variable with integer coefficients, used as generating function.
+///
+/// Giving the weight `x` to some rows and `1` to the others, the coefficient of `x^k` in
+/// the sum is the number of solutions with exactly `k` of those rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Polynomial {
+    /// The coefficients, by increasing degree, without trailing zeros.
+    coefficients: Vec<u128>,
+}
+
+impl Polynomial {
+    /// Returns the polynomial with the given coefficients, by increasing degree.
+    pub fn new(coefficients: impl IntoIterator<Item = u128>) -> Self {
+        let mut coefficients: Vec<_> = coefficients.into_iter().collect();
+
+        while coefficients.last() == Some(&0) {
+            coefficients.pop();
+        }
+
+        Polynomial { coefficients }
+    }
+
+    /// Returns the polynomial `x`.
+    pub fn x() -> Self {
+        Polynomial::new([0, 1])
+    }
+
+    /// Returns the coefficients, by increasing degree, without trailing zeros.
+    pub fn coefficients(&self) -> &[u128] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficient of `x^degree`.
+    pub fn coefficient(&self, degree: usize) -> u128 {
+        self.coefficients.get(degree).copied().unwrap_or(0)
+    }
+}
+
+impl Semiring for Polynomial {
+    fn zero() -> Self {
+        Polynomial::default()
+    }
+
+    fn one() -> Self {
+        Polynomial::new([1])
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let len = self.coefficients.len().max(other.coefficients.len());
+
+        Polynomial::new((0..len).map(|i| self.coefficient(i) + other.coefficient(i)))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut coefficients = vec![0; self.coefficients.len() + other.coefficients.len()];
+
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+
+        Polynomial::new(coefficients)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.coefficients.capacity() * size_of::<u128>()
+    }
+}
@@ -0,0 +1,171 @@
+//! Set cover on a [`DancingLinksMatrix`].
+//!
+//! In set cover mode, every primary column must be covered at least once instead of
+//! exactly once, while secondary columns can still be covered at most once.
+//!
+//! [`DancingLinksMatrix`]: crate::matrix::DancingLinksMatrix
+
+use std::collections::HashMap;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::{ColumnRef, MatrixCellRef},
+    matrix::CellIteratorDir,
+    solver::{FewestRows, RowBounds, SearchFlow, SearchHooks, Solution},
+};
+
+/// A solver looking for set covers of the primary columns of a matrix.
+///
+/// It branches on the uncovered primary column with the fewest rows, like Algorithm X.
+/// When a row is chosen its primary columns are marked as covered, but the rows
+/// intersecting them stay in the matrix. The alternatives already tried for a column
+/// are hidden in the following branches, so that each cover is found only once.
+pub struct SetCoverSolver<'a, T> {
+    matrix: DancingLinksMatrix<'a, T>,
+    bounds: RowBounds,
+}
+
+impl<'a, T> SetCoverSolver<'a, T> {
+    pub fn new(matrix: DancingLinksMatrix<'a, T>) -> Self {
+        Self {
+            bounds: RowBounds::unbounded(&matrix),
+            matrix,
+        }
+    }
+
+    /// Returns all the minimal covers, that is the covers where no row can be removed
+    /// without leaving a primary column uncovered.
+    pub fn solve_minimal(&self) -> Vec<Solution<'a, T>> {
+        let mut hooks = MinimalCovers {
+            solutions: Vec::new(),
+        };
+
+        self.explore(&mut Vec::new(), &mut hooks);
+
+        hooks.solutions
+    }
+
+    /// Returns a cover with the fewest rows, or `None` if some primary column cannot be
+    /// covered.
+    pub fn solve_minimum(&self) -> Option<Solution<'a, T>> {
+        let mut hooks = FewestRows::new(self.bounds);
+
+        self.explore(&mut Vec::new(), &mut hooks);
+
+        hooks.best
+    }
+
+    fn explore(
+        &self,
+        chosen: &mut Vec<MatrixCellRef<'a, T>>,
+        hooks: &mut impl SearchHooks<'a, T>,
+    ) -> SearchFlow {
+        let matrix = &self.matrix;
+
+        if matrix.all_covered() {
+            return hooks.solution(matrix, chosen);
+        }
+
+        let column = matrix.min_column();
+        let rows: Vec<_> = matrix
+            .iterate_cells(column.cell(), CellIteratorDir::Down, false)
+            .collect();
+
+        let mut flow = SearchFlow::Continue;
+        let mut hidden = 0;
+
+        for &row in &rows {
+            let covered = self.choose(row);
+            chosen.push(row);
+
+            if hooks.accept(matrix, chosen) {
+                flow = self.explore(chosen, hooks);
+            }
+
+            chosen.pop();
+            self.unchoose(&covered);
+
+            if flow == SearchFlow::Stop {
+                break;
+            }
+
+            matrix.hide_row(row);
+            hidden += 1;
+        }
+
+        for &row in rows[..hidden].iter().rev() {
+            matrix.unhide_row(row);
+        }
+
+        flow
+    }
+
+    /// Chooses a row, returning the columns covered by it.
+    fn choose(&self, row: MatrixCellRef<'a, T>) -> Vec<ColumnRef<'a, T>> {
+        let matrix = &self.matrix;
+        let mut covered = Vec::new();
+
+        for cell in matrix.iterate_cells(row, CellIteratorDir::Right, true) {
+            let column = cell.column();
+
+            if !column.primary {
+                matrix.cover(column);
+                covered.push(column);
+            } else if !column.covered() {
+                matrix.cover_header(column);
+                covered.push(column);
+            }
+        }
+
+        covered
+    }
+
+    /// Undoes [`Self::choose`].
+    fn unchoose(&self, covered: &[ColumnRef<'a, T>]) {
+        for &column in covered.iter().rev() {
+            if column.primary {
+                self.matrix.uncover_header(column);
+            } else {
+                self.matrix.uncover(column);
+            }
+        }
+    }
+}
+
+/// Hooks collecting the covers without redundant rows.
+struct MinimalCovers<'a, T> {
+    solutions: Vec<Solution<'a, T>>,
+}
+
+impl<'a, T> SearchHooks<'a, T> for MinimalCovers<'a, T> {
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        if is_minimal(matrix, selection) {
+            self.solutions.push(Solution::from_rows(matrix, selection));
+        }
+
+        SearchFlow::Continue
+    }
+}
+
+/// Checks that every row covers a primary column not covered by the other rows.
+fn is_minimal<'a, T>(matrix: &DancingLinksMatrix<'a, T>, rows: &[MatrixCellRef<'a, T>]) -> bool {
+    let primary_cells = |row| {
+        matrix
+            .iterate_cells(row, CellIteratorDir::Right, true)
+            .filter(|cell| cell.column().primary)
+    };
+
+    let mut counts = HashMap::new();
+    for &row in rows {
+        for cell in primary_cells(row) {
+            *counts.entry(cell.column().index).or_insert(0) += 1;
+        }
+    }
+
+    rows.iter()
+        .all(|&row| primary_cells(row).any(|cell| counts[&cell.column().index] == 1))
+}
@@ -1,28 +1,59 @@
 use std::{
-    collections::HashMap,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
     fmt::{Debug, Formatter},
     ptr,
 };
 
 use log::debug;
+use rand::{Rng, SeedableRng, rngs::StdRng};
 
 use crate::{
-    DancingLinksMatrix,
+    DancingLinksMatrix, RestartStrategy,
     cells::{CellRow, ColumnName, ColumnRef, MatrixCellRef},
-    matrix::CellIteratorDir,
+    matrix::{CellIteratorDir, ColumnChoice},
+    prune::{Pruned, Pruner, SharedPruner},
+    split::{Assumed, assume, retract},
+    stepper::Stepper,
+    symmetry::{Canonical, Orbit, SymmetryGroup},
 };
 
 pub struct Solution<'a, T> {
     pub solution_map: HashMap<usize, Vec<&'a T>>,
 }
 
-fn cover_row<'a, T>(matrix: &DancingLinksMatrix<'a, T>, row: MatrixCellRef<'a, T>) {
+impl<'a, T> Solution<'a, T> {
+    /// Creates a solution from the rows chosen during the search.
+    pub(crate) fn from_rows(
+        matrix: &DancingLinksMatrix<'a, T>,
+        rows: &[MatrixCellRef<'a, T>],
+    ) -> Self {
+        let mut sol = HashMap::new();
+
+        for row in rows {
+            let mut tmp_list = Vec::new();
+            for r in matrix.iterate_cells(row, CellIteratorDir::Right, true) {
+                if let ColumnName::Other(name) = r.name() {
+                    tmp_list.push(name);
+                }
+            }
+
+            if let CellRow::Data(row) = row.row {
+                sol.insert(row.into(), tmp_list);
+            }
+        }
+
+        Solution { solution_map: sol }
+    }
+}
+
+pub(crate) fn cover_row<'a, T>(matrix: &DancingLinksMatrix<'a, T>, row: MatrixCellRef<'a, T>) {
     for j in matrix.iterate_cells(row, CellIteratorDir::Right, false) {
         matrix.cover(j.column())
     }
 }
 
-fn uncover_row<'a, T>(matrix: &DancingLinksMatrix<'a, T>, row: MatrixCellRef<'a, T>) {
+pub(crate) fn uncover_row<'a, T>(matrix: &DancingLinksMatrix<'a, T>, row: MatrixCellRef<'a, T>) {
     for j in matrix.iterate_cells(row, CellIteratorDir::Left, false) {
         matrix.uncover(j.column())
     }
@@ -32,6 +63,53 @@ pub struct IterativeAlgorithmXSolver<'a, T> {
     matrix: DancingLinksMatrix<'a, T>,
     choose_min: bool,
     return_first: bool,
+    bounds: RowBounds,
+    seed: u64,
+    restarts: Option<RestartStrategy>,
+    symmetries: Option<SymmetryGroup>,
+    /// Rows that every solution contains.
+    assumptions: Vec<usize>,
+    pruner: Option<SharedPruner<'a, T>>,
+}
+
+/// Bounds on the number of rows of a solution.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RowBounds {
+    pub(crate) min: usize,
+    pub(crate) max: usize,
+    /// The maximum number of primary columns in a row of the matrix.
+    pub(crate) max_row_len: usize,
+}
+
+impl RowBounds {
+    /// Creates bounds accepting any number of rows.
+    pub(crate) fn unbounded<T>(matrix: &DancingLinksMatrix<'_, T>) -> Self {
+        let max_row_len = matrix.primary_row_lengths().into_iter().max().unwrap_or(0);
+
+        RowBounds {
+            min: 0,
+            max: usize::MAX,
+            max_row_len,
+        }
+    }
+
+    /// Returns `true` if a solution with a number of rows within the bounds can still be
+    /// reached after choosing `depth` rows, with `remaining` primary columns left to cover.
+    ///
+    /// At least `remaining / max_row_len` more rows are needed, and at most `remaining`
+    /// more rows can be chosen, since each row covers at least a primary column.
+    pub(crate) fn admits(&self, depth: usize, remaining: usize) -> bool {
+        let needed = match self.max_row_len {
+            0 => 0,
+            len => remaining.div_ceil(len),
+        };
+
+        depth + needed <= self.max && depth + remaining >= self.min
+    }
+
+    pub(crate) fn contains(&self, rows: usize) -> bool {
+        (self.min..=self.max).contains(&rows)
+    }
 }
 
 enum StackElem<'a, T> {
@@ -77,141 +155,563 @@ impl<T> Debug for StackElem<'_, T> {
     }
 }
 
+/// Tells the search whether to go on after a solution has been found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SearchFlow {
+    Continue,
+    Stop,
+}
+
+/// Callbacks invoked by [`search`] while it explores the search tree.
+pub(crate) trait SearchHooks<'a, T> {
+    /// Called after the last row of `selection` has been chosen and its columns covered.
+    ///
+    /// Returns `false` if the branch must be pruned.
+    fn accept(
+        &mut self,
+        _matrix: &DancingLinksMatrix<'a, T>,
+        _selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        true
+    }
+
+    /// Called when the rows in `selection` form an exact cover.
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow;
+
+    /// Checked after each call to [`Self::accept`], returns `true` to abort the search.
+    fn stopped(&self) -> bool {
+        false
+    }
+}
+
+/// Hooks collecting every solution found.
+pub(crate) struct Collector<'a, T> {
+    pub(crate) solutions: Vec<Solution<'a, T>>,
+    return_first: bool,
+    bounds: RowBounds,
+    /// The number of nodes explored.
+    nodes: usize,
+    /// The search is aborted when more than `node_limit` nodes are explored.
+    node_limit: usize,
+}
+
+impl<T> Collector<'_, T> {
+    pub(crate) fn new(return_first: bool, bounds: RowBounds, node_limit: usize) -> Self {
+        Collector {
+            solutions: Vec::new(),
+            return_first,
+            bounds,
+            nodes: 0,
+            node_limit,
+        }
+    }
+}
+
+impl<'a, T> SearchHooks<'a, T> for Collector<'a, T> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        self.nodes += 1;
+
+        self.bounds
+            .admits(selection.len(), matrix.uncovered_count())
+    }
+
+    fn stopped(&self) -> bool {
+        self.nodes > self.node_limit
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        if !self.bounds.contains(selection.len()) {
+            return SearchFlow::Continue;
+        }
+
+        self.solutions.push(Solution::from_rows(matrix, selection));
+
+        if self.return_first {
+            SearchFlow::Stop
+        } else {
+            SearchFlow::Continue
+        }
+    }
+}
+
+/// Hooks keeping the solution with the fewest rows.
+pub(crate) struct FewestRows<'a, T> {
+    pub(crate) best: Option<Solution<'a, T>>,
+    best_rows: usize,
+    bounds: RowBounds,
+}
+
+impl<T> FewestRows<'_, T> {
+    pub(crate) fn new(bounds: RowBounds) -> Self {
+        FewestRows {
+            best: None,
+            best_rows: usize::MAX,
+            bounds,
+        }
+    }
+}
+
+impl<'a, T> SearchHooks<'a, T> for FewestRows<'a, T> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        let bounds = RowBounds {
+            max: self.bounds.max.min(self.best_rows.saturating_sub(1)),
+            ..self.bounds
+        };
+
+        bounds.admits(selection.len(), matrix.uncovered_count())
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        let rows = selection.len();
+
+        if self.bounds.contains(rows) && rows < self.best_rows {
+            self.best = Some(Solution::from_rows(matrix, selection));
+            self.best_rows = rows;
+        }
+
+        SearchFlow::Continue
+    }
+}
+
 impl<'a, T> IterativeAlgorithmXSolver<'a, T> {
     pub fn new(matrix: DancingLinksMatrix<'a, T>, choose_min: bool, return_first: bool) -> Self {
         Self {
+            bounds: RowBounds::unbounded(&matrix),
             matrix,
             choose_min,
             return_first,
+            seed: 0,
+            restarts: None,
+            symmetries: None,
+            assumptions: Vec::new(),
+            pruner: None,
         }
     }
 
-    fn create_sol(
-        &self,
-        k: usize,
-        sol_dict: &hashbrown::HashMap<usize, MatrixCellRef<'a, T>>,
-    ) -> Solution<T> {
-        let mut sol = HashMap::new();
+    /// Sets the seed of the random choices of the solver, so that runs are reproducible.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.matrix.reseed(seed);
+        self
+    }
 
-        for (key, row) in sol_dict.iter() {
-            if *key >= k {
-                continue;
-            }
+    /// Restarts the search with a fresh random order of the rows and random tie-breaks
+    /// between the columns, each time it explores more nodes than the limit given by
+    /// `strategy`.
+    ///
+    /// Restarts are meant to find a solution quickly on hard instances, and work best when
+    /// the solver returns the first solution. When all the solutions are requested, only the
+    /// ones found by the last, complete, attempt are returned.
+    ///
+    /// Returns `self`, for chaining.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy is geometric and its factor is not finite and greater than 1.
+    pub fn with_restarts(mut self, strategy: RestartStrategy) -> Self {
+        strategy.validate();
+        self.restarts = Some(strategy);
+        self
+    }
 
-            let mut tmp_list = Vec::new();
-            for r in self
-                .matrix
-                .iterate_cells(*row, CellIteratorDir::Right, true)
-            {
-                if let ColumnName::Other(name) = r.name() {
-                    tmp_list.push(name);
-                }
-            }
+    fn column_choice(&self) -> ColumnChoice {
+        match (self.choose_min, self.restarts) {
+            (false, _) => ColumnChoice::Random,
+            (true, None) => ColumnChoice::Min,
+            (true, Some(_)) => ColumnChoice::MinRandomTies,
+        }
+    }
 
-            if let CellRow::Data(row) = row.row {
-                sol.insert(row.into(), tmp_list);
+    /// Only accepts solutions with at least `min` rows.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_min_rows(mut self, min: usize) -> Self {
+        self.bounds.min = min;
+        self
+    }
+
+    /// Only accepts solutions with at most `max` rows.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_max_rows(mut self, max: usize) -> Self {
+        self.bounds.max = max;
+        self
+    }
+
+    /// Only accepts solutions with exactly `rows` rows.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_rows(self, rows: usize) -> Self {
+        self.with_min_rows(rows).with_max_rows(rows)
+    }
+
+    /// Only returns one solution for each orbit of solutions under `symmetries`, the one
+    /// whose sorted list of rows is lexicographically the smallest.
+    ///
+    /// The canonical check is done on each solution found, so the search still explores
+    /// the whole tree, but the solutions are filtered before being collected.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_symmetries(mut self, symmetries: SymmetryGroup) -> Self {
+        self.symmetries = Some(symmetries);
+        self
+    }
+
+    /// Only looks for the solutions containing all the given rows, numbered from 1, like
+    /// the rows of a [`SubInstance`].
+    ///
+    /// There is no solution when two of the rows share a column.
+    ///
+    /// Returns `self`, for chaining.
+    ///
+    /// [`SubInstance`]: crate::SubInstance
+    pub fn with_assumptions(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.assumptions = rows.into_iter().collect();
+        self
+    }
+
+    /// Calls `pruner` after each row choice, pruning the branches it rejects.
+    ///
+    /// This enforces side constraints during the search, instead of filtering the
+    /// solutions afterwards.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_pruner(mut self, pruner: impl Pruner<T> + 'a) -> Self {
+        self.pruner = Some(RefCell::new(Box::new(pruner)));
+        self
+    }
+
+    pub fn solve(&self) -> Vec<Solution<'a, T>> {
+        self.solve_orbits()
+            .into_iter()
+            .map(|orbit| orbit.representative)
+            .collect()
+    }
+
+    /// Returns the solutions like [`Self::solve`], with the size of the orbit of each one
+    /// under the symmetries given with [`Self::with_symmetries`].
+    ///
+    /// Without symmetries, every orbit has size 1.
+    pub fn solve_orbits(&self) -> Vec<Orbit<'a, T>> {
+        let Some(strategy) = self.restarts else {
+            return self
+                .attempt(usize::MAX)
+                .expect("search without node limit cannot be aborted");
+        };
+
+        let mut seeds = StdRng::seed_from_u64(self.seed);
+        let mut attempt = 0;
+
+        loop {
+            let node_limit = strategy.node_limit(attempt);
+            debug!("attempt {attempt}, node limit {node_limit}");
+
+            self.matrix.reseed(seeds.r#gen());
+            self.matrix.shuffle_rows();
+
+            if let Some(orbits) = self.attempt(node_limit) {
+                return orbits;
             }
+
+            attempt += 1;
         }
+    }
 
-        Solution { solution_map: sol }
+    /// Runs a search exploring at most `node_limit` nodes, returning `None` if it was
+    /// aborted.
+    fn attempt(&self, node_limit: usize) -> Option<Vec<Orbit<'a, T>>> {
+        let Some(assumed) = assume(&self.matrix, &self.assumptions) else {
+            return Some(Vec::new());
+        };
+
+        let canonical = Canonical {
+            group: self.symmetries.as_ref(),
+            inner: Collector::new(self.return_first, self.bounds, node_limit),
+            orbit_sizes: Vec::new(),
+        };
+        let pruned = Pruned {
+            pruner: self.pruner.as_ref(),
+            inner: canonical,
+        };
+        let mut hooks = Assumed::new(assumed, pruned);
+
+        search(&self.matrix, self.column_choice(), &mut hooks);
+        retract(&self.matrix, &hooks.assumed);
+
+        if hooks.stopped() {
+            return None;
+        }
+
+        let hooks = hooks.inner.inner;
+        let orbits = hooks
+            .inner
+            .solutions
+            .into_iter()
+            .zip(hooks.orbit_sizes)
+            .map(|(representative, size)| Orbit {
+                representative,
+                size,
+            })
+            .collect();
+
+        Some(orbits)
     }
 
-    pub fn solve(&self) -> Vec<Solution<T>> {
-        let mut solutions = Vec::new();
-        let mut sol_dict = hashbrown::HashMap::new();
+    /// Returns a [`Stepper`] running the search one event at a time.
+    ///
+    /// The solver is borrowed mutably, since the stepper modifies the matrix while the
+    /// search is paused.
+    pub fn stepper(&mut self) -> Stepper<'_, 'a, T> {
+        Stepper::new(
+            &self.matrix,
+            self.column_choice(),
+            self.bounds,
+            self.return_first,
+            self.pruner.as_ref(),
+        )
+    }
 
-        let mut advance = false;
+    /// Returns a solution with the fewest rows, or `None` if there is no exact cover
+    /// within the row bounds.
+    ///
+    /// Branches that cannot lead to a solution smaller than the best one found so far
+    /// are pruned.
+    pub fn solve_min_rows(&self) -> Option<Solution<'a, T>> {
+        let assumed = assume(&self.matrix, &self.assumptions)?;
+        let pruned = Pruned {
+            pruner: self.pruner.as_ref(),
+            inner: FewestRows::new(self.bounds),
+        };
+        let mut hooks = Assumed::new(assumed, pruned);
+
+        search(&self.matrix, self.column_choice(), &mut hooks);
+        retract(&self.matrix, &hooks.assumed);
+
+        hooks.inner.inner.best
+    }
+}
+
+/// Runs Algorithm X on `matrix`, reporting the progress to `hooks`.
+///
+/// The matrix is restored to its original state when the function returns.
+pub(crate) fn search<'a, T>(
+    matrix: &DancingLinksMatrix<'a, T>,
+    choice: ColumnChoice,
+    hooks: &mut impl SearchHooks<'a, T>,
+) {
+    let mut state = SearchState::new(false);
+
+    while state.step(matrix, choice, hooks) {}
+}
 
+/// An event of the search, reported by [`Stepper::step`].
+///
+/// Rows are numbered from 1, columns are numbered from 1 in the order they were added to
+/// the builder.
+///
+/// [`Stepper::step`]: crate::Stepper::step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchEvent {
+    /// The column to branch on was chosen, and has `rows` rows. When it has no row, the
+    /// branch is a dead end.
+    ChooseColumn { column: usize, rows: usize },
+    /// The row was added to the selection, which now has `depth` rows.
+    TryRow { row: usize, depth: usize },
+    /// The row was removed from the selection, which had `depth` rows.
+    Backtrack { row: usize, depth: usize },
+    /// The selection is an exact cover.
+    SolutionFound { rows: Vec<usize> },
+}
+
+/// The state of Algorithm X between two iterations of its main loop.
+pub(crate) struct SearchState<'a, T> {
+    stack: Vec<StackElem<'a, T>>,
+    pub(crate) selection: Vec<MatrixCellRef<'a, T>>,
+    /// Whether the current node is done, and the search must move to the next branch.
+    advance: bool,
+    /// The events of the iterations run so far, if they are recorded.
+    pub(crate) events: Option<VecDeque<SearchEvent>>,
+}
+
+impl<'a, T> SearchState<'a, T> {
+    pub(crate) fn new(record: bool) -> Self {
+        SearchState {
+            stack: vec![StackElem::Root],
+            selection: Vec::new(),
+            advance: false,
+            events: record.then(VecDeque::new),
+        }
+    }
+
+    fn record(&mut self, event: impl FnOnce(&[MatrixCellRef<'a, T>]) -> SearchEvent) {
+        if let Some(events) = &mut self.events {
+            events.push_back(event(&self.selection));
+        }
+    }
+
+    /// Runs one iteration of the main loop.
+    ///
+    /// Returns `false` when the search is over, the matrix being restored to its original
+    /// state.
+    pub(crate) fn step(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        choice: ColumnChoice,
+        hooks: &mut impl SearchHooks<'a, T>,
+    ) -> bool {
         use StackElem::*;
-        let mut stack = vec![Root];
 
-        while let Some(elem) = stack.last().copied() {
-            debug!("elem: {elem:?}, advance: {advance}");
-            // trace!("matrix:\n{}", self.matrix);
+        let Some(elem) = self.stack.last().copied() else {
+            return false;
+        };
 
-            let k = elem.k();
+        debug!("elem: {elem:?}, advance: {}", self.advance);
+        // trace!("matrix:\n{}", matrix);
 
-            let column = self.matrix.first_column();
-            let column_cell = column.cell();
+        let k = elem.k();
 
-            if ptr::eq(column_cell.right(), column_cell) {
-                let sol = self.create_sol(k, &sol_dict);
-                solutions.push(sol);
-                if self.return_first {
-                    return solutions;
-                }
-                advance = true;
+        if !self.advance && matrix.all_covered() {
+            self.record(|selection| SearchEvent::SolutionFound {
+                rows: selection.iter().map(|row| row.row.into()).collect(),
+            });
+
+            if hooks.solution(matrix, &self.selection) == SearchFlow::Stop {
+                self.abort(matrix);
+                return false;
             }
+            self.advance = true;
+
+            // Ends the iteration, so that a paused search shows the matrix fully covered.
+            return true;
+        }
 
-            let next_row = match elem {
-                Root if advance => {
-                    stack.pop();
-                    continue;
+        let next_row = match elem {
+            Root if self.advance => {
+                self.stack.pop();
+                return true;
+            }
+            Iteration {
+                current_row,
+                start_row,
+                ..
+            } if self.advance => {
+                self.record(|selection| SearchEvent::Backtrack {
+                    row: current_row.row.into(),
+                    depth: selection.len(),
+                });
+
+                self.stack.pop();
+                self.selection.pop();
+
+                uncover_row(matrix, current_row);
+
+                let next_row = current_row.down();
+                if ptr::eq(next_row, start_row) {
+                    let col = next_row.column();
+                    matrix.uncover(col);
+                    return true;
                 }
-                Iteration {
-                    current_row,
+
+                self.stack.push(Iteration {
+                    k,
+                    current_row: next_row,
                     start_row,
-                    ..
-                } if advance => {
-                    stack.pop();
-
-                    uncover_row(&self.matrix, current_row);
-
-                    let next_row = current_row.down();
-                    if ptr::eq(next_row, start_row) {
-                        let col = next_row.column();
-                        self.matrix.uncover(col);
-                        advance = true;
-                        continue;
-                    } else {
-                        stack.push(Iteration {
-                            k,
-                            current_row: next_row,
-                            start_row,
-                        });
-                        let col = start_row.column();
-                        add_to_sol(&mut sol_dict, k - 1, next_row, col);
-                        advance = false;
-                    }
-                    next_row
-                }
-                _ => {
-                    let start_col = if self.choose_min {
-                        self.matrix.min_column()
-                    } else {
-                        self.matrix.random_column()
-                    };
-                    if start_col.empty() {
-                        advance = true;
-                        continue;
-                    }
-
-                    let col_cell = start_col.cell();
-
-                    self.matrix.cover(start_col);
-
-                    let next_row = col_cell.down();
-                    stack.push(Iteration {
-                        k: k + 1,
-                        current_row: next_row,
-                        start_row: col_cell,
-                    });
-                    advance = false;
-                    add_to_sol(&mut sol_dict, k, next_row, start_col);
-
-                    next_row
+                });
+                add_to_sol(&mut self.selection, k - 1, next_row, start_row.column());
+                next_row
+            }
+            _ => {
+                let start_col = matrix.choose_column(choice);
+
+                self.record(|_| SearchEvent::ChooseColumn {
+                    column: start_col.index,
+                    rows: start_col.size(),
+                });
+
+                if start_col.empty() {
+                    self.advance = true;
+                    return true;
                 }
-            };
 
-            cover_row(&self.matrix, next_row);
+                let col_cell = start_col.cell();
+
+                matrix.cover(start_col);
+
+                let next_row = col_cell.down();
+                self.stack.push(Iteration {
+                    k: k + 1,
+                    current_row: next_row,
+                    start_row: col_cell,
+                });
+                add_to_sol(&mut self.selection, k, next_row, start_col);
+
+                next_row
+            }
+        };
+
+        self.record(|selection| SearchEvent::TryRow {
+            row: next_row.row.into(),
+            depth: selection.len(),
+        });
+
+        cover_row(matrix, next_row);
+
+        self.advance = !hooks.accept(matrix, &self.selection);
+
+        if hooks.stopped() {
+            self.abort(matrix);
+            return false;
         }
 
-        solutions
+        true
+    }
+
+    /// Ends the search, undoing all the choices still on the stack.
+    pub(crate) fn abort(&mut self, matrix: &DancingLinksMatrix<'a, T>) {
+        unwind(matrix, &mut self.stack);
+        self.selection.clear();
+    }
+}
+
+/// Undoes all the choices still on the stack, in reverse order.
+fn unwind<'a, T>(matrix: &DancingLinksMatrix<'a, T>, stack: &mut Vec<StackElem<'a, T>>) {
+    while let Some(elem) = stack.pop() {
+        if let StackElem::Iteration {
+            current_row,
+            start_row,
+            ..
+        } = elem
+        {
+            uncover_row(matrix, current_row);
+            matrix.uncover(start_row.column());
+        }
     }
 }
 
 fn add_to_sol<'a, T>(
-    sol_dict: &mut hashbrown::HashMap<usize, MatrixCellRef<'a, T>>,
+    selection: &mut Vec<MatrixCellRef<'a, T>>,
     k: usize,
     next_row: MatrixCellRef<'a, T>,
     current_col: ColumnRef<'a, T>,
@@ -225,7 +725,8 @@ fn add_to_sol<'a, T>(
 
     if cfg!(debug_assertions) {
         debug_assert!(next_row.column().cell().index != next_row.index);
+        debug_assert_eq!(selection.len(), k);
     }
 
-    sol_dict.insert(k, next_row);
+    selection.push(next_row);
 }
@@ -0,0 +1,280 @@
+//! Splitting a search into independent sub-instances, to solve them in separate processes.
+//!
+//! [`SubInstance::split`] expands the top levels of the search tree, and returns the
+//! prefixes of chosen rows reached. Each prefix is written on a line of text, so that
+//! another process can read it and solve it with
+//! [`IterativeAlgorithmXSolver::with_assumptions`]. The [`PartialResult`] of each process
+//! are then merged into the result of the whole instance.
+//!
+//! [`IterativeAlgorithmXSolver::with_assumptions`]: crate::IterativeAlgorithmXSolver::with_assumptions
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::CellIteratorDir,
+    solver::{SearchFlow, SearchHooks, Solution, cover_row, uncover_row},
+};
+
+/// A part of the search, made of the solutions containing some given rows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubInstance {
+    /// The indexes of the rows chosen, numbered from 1.
+    pub rows: Vec<usize>,
+}
+
+impl SubInstance {
+    /// Splits the search on `matrix` into at most `max_instances` sub-instances, expanding
+    /// at most `max_depth` levels of the search tree.
+    ///
+    /// Each level chooses the column with the fewest rows, like the solver. A level is only
+    /// expanded if the number of sub-instances stays within `max_instances`. The prefixes
+    /// leading to a dead end are dropped, so every solution is in exactly one of the
+    /// returned sub-instances, and an instance without solution may return none.
+    pub fn split<T>(
+        matrix: &DancingLinksMatrix<'_, T>,
+        max_instances: usize,
+        max_depth: usize,
+    ) -> Vec<SubInstance> {
+        let mut frontier = vec![Vec::new()];
+
+        for _ in 0..max_depth {
+            if frontier.len() >= max_instances {
+                break;
+            }
+
+            let mut next = Vec::new();
+
+            for prefix in &frontier {
+                let chosen = assume(matrix, prefix).expect("prefixes never conflict");
+
+                if matrix.all_covered() {
+                    next.push(prefix.clone());
+                } else {
+                    let column = matrix.min_column();
+
+                    for row in matrix.iterate_cells(column.cell(), CellIteratorDir::Down, false) {
+                        let mut rows = prefix.clone();
+                        rows.push(row.row.into());
+                        next.push(rows);
+                    }
+                }
+
+                retract(matrix, &chosen);
+            }
+
+            if next.len() > max_instances || next == frontier {
+                break;
+            }
+
+            frontier = next;
+        }
+
+        frontier
+            .into_iter()
+            .map(|rows| SubInstance { rows })
+            .collect()
+    }
+
+    /// Writes the sub-instances, one per line.
+    pub fn write_all(instances: &[SubInstance], mut writer: impl Write) -> io::Result<()> {
+        for instance in instances {
+            writeln!(writer, "{instance}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads sub-instances written by [`Self::write_all`].
+    pub fn read_all(reader: impl BufRead) -> io::Result<Vec<SubInstance>> {
+        reader
+            .lines()
+            .map(|line| {
+                line?
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SubInstance {
+    /// Formats the sub-instance as its rows separated by spaces.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rows.iter().join(" "))
+    }
+}
+
+impl FromStr for SubInstance {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s.split_whitespace().map(str::parse).try_collect()?;
+
+        Ok(SubInstance { rows })
+    }
+}
+
+/// The result of solving some sub-instances: the number of solutions, and the solutions
+/// themselves if they were kept.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialResult {
+    pub count: u128,
+    /// The solutions, as sorted lists of row indexes.
+    pub solutions: Vec<Vec<usize>>,
+}
+
+impl PartialResult {
+    /// Creates the result holding `solutions`.
+    pub fn from_solutions<T>(solutions: &[Solution<'_, T>]) -> Self {
+        PartialResult {
+            count: solutions.len() as u128,
+            solutions: solutions
+                .iter()
+                .map(|solution| solution.solution_map.keys().copied().sorted().collect())
+                .collect(),
+        }
+    }
+
+    /// Merges the results of disjoint sub-instances.
+    pub fn merge(results: impl IntoIterator<Item = PartialResult>) -> Self {
+        results
+            .into_iter()
+            .fold(PartialResult::default(), |mut merged, result| {
+                merged.count += result.count;
+                merged.solutions.extend(result.solutions);
+                merged
+            })
+    }
+
+    /// Writes the result in a textual format.
+    ///
+    /// The first line contains `count N`, then each line contains a solution as its rows
+    /// separated by spaces.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "count {}", self.count)?;
+
+        for solution in &self.solutions {
+            writeln!(writer, "{}", solution.iter().join(" "))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a result written by [`Self::write`].
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+
+        let mut lines = reader.lines();
+        let header = lines.next().transpose()?.unwrap_or_default();
+
+        let count = header
+            .strip_prefix("count ")
+            .ok_or_else(|| invalid("missing count".into()))?
+            .parse()
+            .map_err(|e: ParseIntError| invalid(e.to_string()))?;
+
+        let solutions = lines
+            .map(|line| {
+                line?
+                    .split_whitespace()
+                    .map(str::parse)
+                    .try_collect()
+                    .map_err(|e: ParseIntError| invalid(e.to_string()))
+            })
+            .try_collect()?;
+
+        Ok(PartialResult { count, solutions })
+    }
+}
+
+/// Chooses the given rows, returning their cells, or `None` if two of them share a column.
+///
+/// The matrix is left untouched when `None` is returned.
+pub(crate) fn assume<'a, T>(
+    matrix: &DancingLinksMatrix<'a, T>,
+    rows: &[usize],
+) -> Option<Vec<MatrixCellRef<'a, T>>> {
+    let mut chosen = Vec::with_capacity(rows.len());
+
+    for &index in rows {
+        let row = matrix
+            .row_cell(index)
+            .unwrap_or_else(|| panic!("Row {index} does not exist"));
+
+        if !matrix.is_row_active(row) {
+            retract(matrix, &chosen);
+            return None;
+        }
+
+        matrix.cover(row.column());
+        cover_row(matrix, row);
+        chosen.push(row);
+    }
+
+    Some(chosen)
+}
+
+/// Undoes [`assume`].
+pub(crate) fn retract<'a, T>(matrix: &DancingLinksMatrix<'a, T>, chosen: &[MatrixCellRef<'a, T>]) {
+    for &row in chosen.iter().rev() {
+        uncover_row(matrix, row);
+        matrix.uncover(row.column());
+    }
+}
+
+/// Hooks adding the assumed rows in front of the selection passed to `inner`.
+pub(crate) struct Assumed<'a, H, T> {
+    pub(crate) assumed: Vec<MatrixCellRef<'a, T>>,
+    pub(crate) inner: H,
+    /// The assumed rows followed by the current selection, reused between calls.
+    buffer: Vec<MatrixCellRef<'a, T>>,
+}
+
+impl<'a, H, T> Assumed<'a, H, T> {
+    pub(crate) fn new(assumed: Vec<MatrixCellRef<'a, T>>, inner: H) -> Self {
+        Assumed {
+            assumed,
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn full_selection(&mut self, selection: &[MatrixCellRef<'a, T>]) {
+        self.buffer.clear();
+        self.buffer.extend_from_slice(&self.assumed);
+        self.buffer.extend_from_slice(selection);
+    }
+}
+
+impl<'a, T, H: SearchHooks<'a, T>> SearchHooks<'a, T> for Assumed<'a, H, T> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        self.full_selection(selection);
+        self.inner.accept(matrix, &self.buffer)
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        self.full_selection(selection);
+        self.inner.solution(matrix, &self.buffer)
+    }
+
+    fn stopped(&self) -> bool {
+        self.inner.stopped()
+    }
+}
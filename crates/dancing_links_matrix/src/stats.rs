@@ -0,0 +1,243 @@
+//! Statistics and diagnostics of a matrix, to inspect it before solving.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::{CellRow, ColumnInfo, MatrixCell},
+};
+
+/// A summary of a distribution of sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    /// The number of occurrences of each size, by increasing size.
+    pub histogram: BTreeMap<usize, usize>,
+}
+
+impl Distribution {
+    fn new(sizes: impl IntoIterator<Item = usize>) -> Self {
+        let histogram: BTreeMap<_, _> = sizes.into_iter().counts().into_iter().collect();
+        let count: usize = histogram.values().sum();
+        let total: usize = histogram.iter().map(|(size, times)| size * times).sum();
+
+        Distribution {
+            min: histogram.keys().next().copied().unwrap_or(0),
+            max: histogram.keys().next_back().copied().unwrap_or(0),
+            mean: if count == 0 {
+                0.0
+            } else {
+                total as f64 / count as f64
+            },
+            histogram,
+        }
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {}, mean {:.2}, max {}",
+            self.min, self.mean, self.max
+        )
+    }
+}
+
+/// A problem found by [`DancingLinksMatrix::diagnose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic<'m, T> {
+    /// A primary column in no row: the matrix has no solution.
+    EmptyPrimaryColumn(&'m T),
+    /// A secondary column in no row, which constrains nothing.
+    UnusedSecondaryColumn(&'m T),
+    /// Rows with the same columns, by increasing index: each solution using one of them
+    /// is found once for each of them.
+    DuplicateRows(Vec<usize>),
+}
+
+impl<T: fmt::Display> fmt::Display for Diagnostic<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::EmptyPrimaryColumn(name) => {
+                write!(
+                    f,
+                    "primary column {name} is in no row, there is no solution"
+                )
+            }
+            Diagnostic::UnusedSecondaryColumn(name) => {
+                write!(f, "secondary column {name} is in no row")
+            }
+            Diagnostic::DuplicateRows(rows) => {
+                write!(f, "rows {} have the same columns", rows.iter().join(", "))
+            }
+        }
+    }
+}
+
+/// The report of [`DancingLinksMatrix::stats`].
+///
+/// The statistics are computed on the matrix as it was built, whatever rows are chosen
+/// at the time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixStats<'m, T> {
+    pub rows: usize,
+    pub primary_columns: usize,
+    pub secondary_columns: usize,
+    /// The number of cells of the matrix, i.e. the number of 1 in the 0/1 matrix.
+    pub cells: usize,
+    /// The fraction of the entries of the 0/1 matrix that are 1.
+    pub density: f64,
+    /// The number of rows of the columns.
+    pub column_sizes: Distribution,
+    /// The number of columns of the rows.
+    pub row_lengths: Distribution,
+    /// An estimate of the memory used by the matrix, in bytes.
+    ///
+    /// It counts the cells, the columns and their indexes, but not the memory owned by the
+    /// column names.
+    pub memory_bytes: usize,
+    /// The problems found in the matrix.
+    pub diagnostics: Vec<Diagnostic<'m, T>>,
+}
+
+impl<'m, T> MatrixStats<'m, T> {
+    pub(crate) fn new(matrix: &'m DancingLinksMatrix<'_, T>) -> Self {
+        let columns = &matrix.columns[1..];
+        let primary_columns = columns.iter().filter(|column| column.primary).count();
+        let data_cells = data_rows(matrix).map(|row| row.len()).sum::<usize>();
+
+        let sizes = column_sizes(matrix);
+
+        let entries = matrix.row_count * matrix.column_count;
+        let memory_bytes = matrix.cells.len()
+            * (size_of::<MatrixCell<'_, T>>() + size_of::<usize>())
+            + matrix.columns.len() * (size_of::<ColumnInfo<'_, T>>() + size_of::<usize>())
+            + matrix.row_weights.len() * size_of::<f64>()
+            + size_of_val(matrix);
+
+        MatrixStats {
+            rows: matrix.row_count,
+            primary_columns,
+            secondary_columns: matrix.column_count - primary_columns,
+            cells: data_cells,
+            density: if entries == 0 {
+                0.0
+            } else {
+                data_cells as f64 / entries as f64
+            },
+            column_sizes: Distribution::new(sizes[1..].iter().copied()),
+            row_lengths: Distribution::new(data_rows(matrix).map(|row| row.len())),
+            memory_bytes,
+            diagnostics: diagnostics(matrix, &sizes),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MatrixStats<'_, T> {
+    /// Formats the report with one statistic per line, followed by the problems found.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "rows: {}", self.rows)?;
+        writeln!(
+            f,
+            "columns: {} ({} primary, {} secondary)",
+            self.primary_columns + self.secondary_columns,
+            self.primary_columns,
+            self.secondary_columns
+        )?;
+        writeln!(
+            f,
+            "cells: {} (density {:.2}%)",
+            self.cells,
+            self.density * 100.0
+        )?;
+        writeln!(f, "column sizes: {}", self.column_sizes)?;
+        writeln!(f, "row lengths: {}", self.row_lengths)?;
+        write!(f, "memory: {:.1} KiB", self.memory_bytes as f64 / 1024.0)?;
+
+        if self.diagnostics.is_empty() {
+            write!(f, "\nno problem found")
+        } else {
+            for diagnostic in &self.diagnostics {
+                write!(f, "\n{diagnostic}")?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Returns the problems of `matrix`.
+pub(crate) fn diagnose<'m, T>(matrix: &'m DancingLinksMatrix<'_, T>) -> Vec<Diagnostic<'m, T>> {
+    diagnostics(matrix, &column_sizes(matrix))
+}
+
+/// Returns the problems of `matrix`, given the number of rows of each column.
+fn diagnostics<'m, T>(
+    matrix: &'m DancingLinksMatrix<'_, T>,
+    sizes: &[usize],
+) -> Vec<Diagnostic<'m, T>> {
+    let mut diagnostics = Vec::new();
+
+    for column in &matrix.columns[1..] {
+        if sizes[column.index] > 0 {
+            continue;
+        }
+
+        let name = column.name.get_name().unwrap();
+        diagnostics.push(if column.primary {
+            Diagnostic::EmptyPrimaryColumn(name)
+        } else {
+            Diagnostic::UnusedSecondaryColumn(name)
+        });
+    }
+
+    let mut rows: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    for row in data_rows(matrix) {
+        let columns = row
+            .iter()
+            .map(|cell| cell.column().index)
+            .sorted()
+            .collect();
+        rows.entry(columns).or_default().push(row[0].row.into());
+    }
+
+    diagnostics.extend(
+        rows.into_values()
+            .filter(|rows| rows.len() > 1)
+            .sorted()
+            .map(Diagnostic::DuplicateRows),
+    );
+
+    diagnostics
+}
+
+/// Returns the number of rows of each column of `matrix`, indexed by column index.
+fn column_sizes<T>(matrix: &DancingLinksMatrix<'_, T>) -> Vec<usize> {
+    let mut sizes = vec![0; matrix.column_count + 1];
+
+    for row in data_rows(matrix) {
+        for cell in row {
+            sizes[cell.column().index] += 1;
+        }
+    }
+
+    sizes
+}
+
+/// Returns the cells of each row of `matrix`, by increasing row index.
+fn data_rows<'m, 'a, T>(
+    matrix: &'m DancingLinksMatrix<'a, T>,
+) -> impl Iterator<Item = &'m [&'a MatrixCell<'a, T>]> {
+    matrix
+        .cells
+        .chunk_by(|a, b| a.row == b.row)
+        .filter(|row| matches!(row[0].row, CellRow::Data(_)))
+}
@@ -0,0 +1,132 @@
+//! Running the search one event at a time.
+
+use crate::{
+    DancingLinksMatrix,
+    cells::MatrixCellRef,
+    matrix::ColumnChoice,
+    prune::{Pruned, SharedPruner},
+    solver::{RowBounds, SearchEvent, SearchFlow, SearchHooks, SearchState},
+};
+
+/// A search of [`IterativeAlgorithmXSolver`] that can be paused after each event.
+///
+/// The stepper runs the same loop as [`IterativeAlgorithmXSolver::solve`], with the same
+/// column choice, row bounds and pruner, but returns control after each [`SearchEvent`].
+/// The restart strategy, the symmetries and the assumptions of the solver are not used.
+///
+/// Dropping the stepper before the end of the search restores the matrix.
+///
+/// [`IterativeAlgorithmXSolver`]: crate::IterativeAlgorithmXSolver
+/// [`IterativeAlgorithmXSolver::solve`]: crate::IterativeAlgorithmXSolver::solve
+pub struct Stepper<'s, 'a, T> {
+    matrix: &'s DancingLinksMatrix<'a, T>,
+    choice: ColumnChoice,
+    bounds: RowBounds,
+    return_first: bool,
+    pruner: Option<&'s SharedPruner<'a, T>>,
+    state: SearchState<'a, T>,
+    /// Whether the search must end at the next step.
+    stop: bool,
+}
+
+impl<'s, 'a, T> Stepper<'s, 'a, T> {
+    pub(crate) fn new(
+        matrix: &'s DancingLinksMatrix<'a, T>,
+        choice: ColumnChoice,
+        bounds: RowBounds,
+        return_first: bool,
+        pruner: Option<&'s SharedPruner<'a, T>>,
+    ) -> Self {
+        Stepper {
+            matrix,
+            choice,
+            bounds,
+            return_first,
+            pruner,
+            state: SearchState::new(true),
+            stop: false,
+        }
+    }
+
+    /// Runs the search until the next event, and returns it, or `None` once the search is
+    /// over.
+    ///
+    /// Solutions outside of the row bounds of the solver are not reported.
+    pub fn step(&mut self) -> Option<SearchEvent> {
+        loop {
+            let events = self.state.events.as_mut().unwrap();
+
+            if let Some(event) = events.pop_front() {
+                if let SearchEvent::SolutionFound { rows } = &event {
+                    if !self.bounds.contains(rows.len()) {
+                        continue;
+                    }
+
+                    self.stop = self.return_first;
+                }
+
+                return Some(event);
+            }
+
+            if self.stop {
+                self.state.abort(self.matrix);
+                return None;
+            }
+
+            let mut hooks = Pruned {
+                pruner: self.pruner,
+                inner: Bounded {
+                    bounds: self.bounds,
+                },
+            };
+
+            self.stop = !self.state.step(self.matrix, self.choice, &mut hooks);
+        }
+    }
+
+    /// Returns the matrix, in its current state.
+    ///
+    /// After a [`SearchEvent::SolutionFound`], all the primary columns are covered.
+    pub fn matrix(&self) -> &DancingLinksMatrix<'a, T> {
+        self.matrix
+    }
+
+    /// Returns the rows of the current selection, numbered from 1.
+    pub fn selection(&self) -> Vec<usize> {
+        self.state
+            .selection
+            .iter()
+            .map(|row| row.row.into())
+            .collect()
+    }
+}
+
+impl<T> Drop for Stepper<'_, '_, T> {
+    fn drop(&mut self) {
+        self.state.abort(self.matrix);
+    }
+}
+
+/// Hooks pruning the branches outside of the row bounds.
+struct Bounded {
+    bounds: RowBounds,
+}
+
+impl<'a, T> SearchHooks<'a, T> for Bounded {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        self.bounds
+            .admits(selection.len(), matrix.uncovered_count())
+    }
+
+    fn solution(
+        &mut self,
+        _matrix: &DancingLinksMatrix<'a, T>,
+        _selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        SearchFlow::Continue
+    }
+}
@@ -0,0 +1,200 @@
+//! # Streaming Matrix Builder
+//!
+//! [`MatrixRowBuilder`] keeps a prototype of the whole matrix, with the links stored as
+//! indexes, and copies it into the arena when the matrix is built, so both copies are in
+//! memory at the end of the build. The [`StreamingBuilder`] allocates each cell in the
+//! arena as soon as its row is added, and links it right away, so the cells are only
+//! stored once.
+//!
+//! On a matrix of a million cells, this lowers the peak memory of the build from 1.7 to
+//! 1.1 times the memory of the matrix, or 1.0 times when the size of the matrix is given
+//! as a hint: see the `peak_memory` example.
+//!
+//! [`MatrixRowBuilder`]: crate::MatrixRowBuilder
+
+use std::cell::RefCell;
+
+use itertools::Itertools;
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    Arena, DancingLinksMatrix,
+    cells::{CellRow, ColumnInfo, ColumnName, ColumnRef, MatrixCell, MatrixCellRef},
+    matrix::ColumnSpec,
+    queue::ColumnPriorityQueue,
+};
+
+/// A builder writing the cells of a [`DancingLinksMatrix`] directly into an arena.
+///
+/// The rows are added by column indexes, numbered from 1 in the order the columns were
+/// given. The builder can be given the expected size of the matrix with
+/// [`Self::with_capacity`], to allocate its indexes once.
+pub struct StreamingBuilder<'a, A, T> {
+    arena: &'a A,
+    columns: Vec<ColumnRef<'a, T>>,
+    cells: Vec<MatrixCellRef<'a, T>>,
+    row_count: usize,
+    row_weights: Vec<f64>,
+}
+
+impl<'a, A: Arena, T> StreamingBuilder<'a, A, T> {
+    /// Creates a builder allocating the cells of the matrix in `arena`, with the given
+    /// columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column.
+    pub fn new(arena: &'a A, columns: impl IntoIterator<Item = impl Into<ColumnSpec<T>>>) -> Self {
+        let specs = columns.into_iter().map(Into::into).collect_vec();
+        if specs.is_empty() {
+            panic!("No columns were added");
+        }
+
+        let mut builder = StreamingBuilder {
+            arena,
+            columns: Vec::with_capacity(specs.len() + 1),
+            cells: Vec::with_capacity(specs.len() + 1),
+            row_count: 0,
+            row_weights: Vec::new(),
+        };
+
+        let first = builder.add_column(ColumnName::First, true);
+        let mut prev = first;
+
+        for spec in specs {
+            let header = builder.add_column(ColumnName::Other(spec.name), spec.primary);
+
+            if spec.primary {
+                prev.link_right(header);
+                prev = header;
+            }
+        }
+
+        prev.link_right(first);
+
+        builder
+    }
+
+    /// Reserves room for `rows` rows with `cells` cells in total, on top of the rows
+    /// already added.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_capacity(mut self, rows: usize, cells: usize) -> Self {
+        self.cells.reserve(cells);
+        self.row_weights.reserve(rows);
+        self
+    }
+
+    /// Adds a row using column indexes.
+    ///
+    /// Use `add_sorted_row_index` if the indexes are already sorted, to avoid sorting them
+    /// twice.
+    pub fn add_row_index(self, row: impl IntoIterator<Item = usize>) -> Self {
+        let mut sorted = row.into_iter().collect_vec();
+        sorted.sort_unstable();
+        self.add_sorted_row_index(sorted)
+    }
+
+    /// Adds a row using sorted column indexes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is empty or if an index is not a column.
+    pub fn add_sorted_row_index(mut self, row: impl IntoIterator<Item = usize>) -> Self {
+        let row_index = CellRow::from(self.row_count + 1);
+        let mut first = None;
+        let mut prev: Option<MatrixCellRef<'a, T>> = None;
+
+        for index in row {
+            assert!(
+                (1..self.columns.len()).contains(&index),
+                "Column {index} does not exist"
+            );
+
+            let column = self.columns[index];
+            let cell = self.alloc_cell(row_index, column);
+
+            let header = column.cell();
+            header.up().link_down(cell);
+            cell.link_down(header);
+            column.increase_size();
+
+            match prev {
+                Some(prev) => prev.link_right(cell),
+                None => first = Some(cell),
+            }
+            prev = Some(cell);
+        }
+
+        let (Some(first), Some(last)) = (first, prev) else {
+            panic!("A row must have at least one column");
+        };
+        last.link_right(first);
+
+        self.row_count += 1;
+        self.row_weights.push(1.0);
+        self
+    }
+
+    /// Sets the weight of the last row added, like [`MatrixRowBuilder::with_weight`].
+    ///
+    /// [`MatrixRowBuilder::with_weight`]: crate::MatrixRowBuilder::with_weight
+    pub fn with_weight(mut self, weight: f64) -> Self {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "Row weight must be finite and non negative, got {weight}"
+        );
+
+        match self.row_weights.last_mut() {
+            Some(last) => *last = weight,
+            None => panic!("No rows were added"),
+        }
+
+        self
+    }
+
+    /// Builds the [`DancingLinksMatrix`] from the columns and rows added.
+    ///
+    /// The cells are already in the arena, so this only moves the indexes of the builder
+    /// into the matrix.
+    pub fn build(self) -> DancingLinksMatrix<'a, T> {
+        let columns_queue = ColumnPriorityQueue::new();
+
+        for &column in self.columns.iter().skip(1) {
+            if column.primary {
+                columns_queue.push(column);
+            }
+        }
+
+        DancingLinksMatrix {
+            column_count: self.columns.len() - 1,
+            columns: self.columns.into_boxed_slice(),
+            cells: self.cells.into_boxed_slice(),
+            row_count: self.row_count,
+            columns_queue,
+            row_weights: self.row_weights.into_boxed_slice(),
+            rng: RefCell::new(StdRng::seed_from_u64(0)),
+        }
+    }
+
+    /// Adds a column with its header cell, linked to itself, and returns the header.
+    fn add_column(&mut self, name: ColumnName<T>, primary: bool) -> MatrixCellRef<'a, T> {
+        let index = self.columns.len();
+        let column = self.arena.alloc(ColumnInfo::new(name, index, 0, primary));
+        self.columns.push(column);
+
+        let header = self.alloc_cell(CellRow::Header, column);
+        column.update_pointer(header);
+
+        header
+    }
+
+    /// Allocates a cell of `column`, linked to itself in every direction.
+    fn alloc_cell(&mut self, row: CellRow, column: ColumnRef<'a, T>) -> MatrixCellRef<'a, T> {
+        let cell = self.arena.alloc(MatrixCell::new(self.cells.len(), row));
+        cell.update_pointers(cell, cell, cell, cell, column);
+        self.cells.push(cell);
+
+        cell
+    }
+}
@@ -0,0 +1,287 @@
+//! Symmetry breaking with user supplied automorphisms of the matrix.
+//!
+//! Many exact cover problems, like N-queens or polyomino tilings, have symmetries mapping
+//! each solution to another solution. Given the group of the symmetries, the solver can
+//! return only one representative for each orbit of solutions: the solution whose sorted
+//! list of rows is lexicographically the smallest in its orbit.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    DancingLinksMatrix, Solution,
+    cells::MatrixCellRef,
+    matrix::CellIteratorDir,
+    solver::{Collector, SearchFlow, SearchHooks},
+};
+
+/// The maximum number of elements of a [`SymmetryGroup`].
+const MAX_GROUP_SIZE: usize = 100_000;
+
+/// A symmetry of a matrix, given as a permutation of its rows and optionally of its columns.
+///
+/// Rows and columns are numbered from 1, in the order they were added to the builder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symmetry {
+    /// The image of each row, indexed by row index - 1.
+    rows: Vec<usize>,
+    /// The image of each column, indexed by column index - 1.
+    columns: Option<Vec<usize>>,
+}
+
+impl Symmetry {
+    /// Creates a symmetry from the permutation of the rows.
+    ///
+    /// The `i`-th element is the image of the row `i + 1`.
+    pub fn from_rows(rows: impl IntoIterator<Item = usize>) -> Self {
+        Symmetry {
+            rows: rows.into_iter().collect(),
+            columns: None,
+        }
+    }
+
+    /// Adds the permutation of the columns, used to check that the symmetry is an
+    /// automorphism of the matrix.
+    ///
+    /// The `i`-th element is the image of the column `i + 1`.
+    ///
+    /// Returns `self`, for chaining.
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = usize>) -> Self {
+        self.columns = Some(columns.into_iter().collect());
+        self
+    }
+
+    /// Returns the image of the row with index `row`.
+    pub fn map_row(&self, row: usize) -> usize {
+        self.rows[row - 1]
+    }
+}
+
+/// An error found while creating a [`SymmetryGroup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymmetryError {
+    /// The permutation of the rows of a symmetry is not a permutation of the matrix rows.
+    InvalidRowPermutation { symmetry: usize },
+    /// The permutation of the columns of a symmetry is not a permutation of the matrix
+    /// columns, or it does not preserve the primary columns.
+    InvalidColumnPermutation { symmetry: usize },
+    /// A symmetry maps a row to a row that does not contain the images of its columns.
+    NotAnAutomorphism { symmetry: usize, row: usize },
+    /// The group generated by the symmetries has more than the allowed number of elements.
+    GroupTooLarge { limit: usize },
+}
+
+impl fmt::Display for SymmetryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymmetryError::InvalidRowPermutation { symmetry } => {
+                write!(f, "symmetry {symmetry} is not a permutation of the rows")
+            }
+            SymmetryError::InvalidColumnPermutation { symmetry } => {
+                write!(f, "symmetry {symmetry} is not a permutation of the columns")
+            }
+            SymmetryError::NotAnAutomorphism { symmetry, row } => write!(
+                f,
+                "symmetry {symmetry} does not map row {row} to a row with the images of its columns"
+            ),
+            SymmetryError::GroupTooLarge { limit } => {
+                write!(f, "the symmetry group has more than {limit} elements")
+            }
+        }
+    }
+}
+
+impl Error for SymmetryError {}
+
+/// A group of symmetries of a matrix, generated by a list of [`Symmetry`].
+#[derive(Debug, Clone)]
+pub struct SymmetryGroup {
+    /// All the elements of the group, identity included, as row permutations.
+    elements: Vec<Vec<usize>>,
+}
+
+impl SymmetryGroup {
+    /// Creates the group generated by `generators`.
+    ///
+    /// Each symmetry is checked to be a permutation of the rows of `matrix`, and, when the
+    /// permutation of the columns is given, to be an automorphism of the matrix.
+    pub fn generate<T>(
+        matrix: &DancingLinksMatrix<'_, T>,
+        generators: impl IntoIterator<Item = Symmetry>,
+    ) -> Result<Self, SymmetryError> {
+        let generators = generators.into_iter().collect_vec();
+
+        for (i, symmetry) in generators.iter().enumerate() {
+            check_symmetry(matrix, i, symmetry)?;
+        }
+
+        let identity = (1..=matrix.row_count).collect_vec();
+
+        let mut elements = HashSet::from([identity.clone()]);
+        let mut queue = VecDeque::from([identity]);
+
+        while let Some(element) = queue.pop_front() {
+            for generator in &generators {
+                let composed = element.iter().map(|&r| generator.map_row(r)).collect_vec();
+
+                if elements.insert(composed.clone()) {
+                    if elements.len() > MAX_GROUP_SIZE {
+                        return Err(SymmetryError::GroupTooLarge {
+                            limit: MAX_GROUP_SIZE,
+                        });
+                    }
+                    queue.push_back(composed);
+                }
+            }
+        }
+
+        Ok(SymmetryGroup {
+            elements: elements.into_iter().collect(),
+        })
+    }
+
+    /// Returns the number of elements of the group.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the group has no elements. A group always contains the identity.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the size of the orbit of a set of rows, if the set is the lexicographically
+    /// smallest in its orbit, or `None` otherwise.
+    pub(crate) fn canonical_orbit(&self, rows: &[usize]) -> Option<usize> {
+        let rows = rows.iter().copied().sorted().collect_vec();
+        let mut orbit = HashSet::new();
+
+        for element in &self.elements {
+            let image = rows.iter().map(|&r| element[r - 1]).sorted().collect_vec();
+
+            if image < rows {
+                return None;
+            }
+
+            orbit.insert(image);
+        }
+
+        Some(orbit.len())
+    }
+}
+
+fn check_symmetry<T>(
+    matrix: &DancingLinksMatrix<'_, T>,
+    index: usize,
+    symmetry: &Symmetry,
+) -> Result<(), SymmetryError> {
+    if !is_permutation(&symmetry.rows, matrix.row_count) {
+        return Err(SymmetryError::InvalidRowPermutation { symmetry: index });
+    }
+
+    let Some(columns) = &symmetry.columns else {
+        return Ok(());
+    };
+
+    let preserves_primary = || {
+        columns
+            .iter()
+            .enumerate()
+            .all(|(i, &c)| matrix.columns[i + 1].primary == matrix.columns[c].primary)
+    };
+
+    if !is_permutation(columns, matrix.column_count) || !preserves_primary() {
+        return Err(SymmetryError::InvalidColumnPermutation { symmetry: index });
+    }
+
+    let row_columns = |row: usize| {
+        matrix
+            .iterate_cells(matrix.row_cell(row).unwrap(), CellIteratorDir::Right, true)
+            .map(|cell| cell.column().index)
+            .collect::<HashSet<_>>()
+    };
+
+    for row in 1..=matrix.row_count {
+        let mapped: HashSet<_> = row_columns(row)
+            .into_iter()
+            .map(|c| columns[c - 1])
+            .collect();
+
+        if mapped != row_columns(symmetry.map_row(row)) {
+            return Err(SymmetryError::NotAnAutomorphism {
+                symmetry: index,
+                row,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn is_permutation(values: &[usize], n: usize) -> bool {
+    values.len() == n && values.iter().all(|&v| (1..=n).contains(&v)) && values.iter().all_unique()
+}
+
+/// A solution representing an orbit of solutions under a [`SymmetryGroup`].
+pub struct Orbit<'a, T> {
+    /// The lexicographically smallest solution of the orbit.
+    pub representative: Solution<'a, T>,
+    /// The number of distinct solutions in the orbit.
+    pub size: usize,
+}
+
+/// Hooks passing to `inner` only the canonical solutions, recording the size of the orbit
+/// of each solution `inner` keeps.
+pub(crate) struct Canonical<'g, 'a, T> {
+    pub(crate) group: Option<&'g SymmetryGroup>,
+    pub(crate) inner: Collector<'a, T>,
+    /// The orbit size of each solution of `inner`, in the same order.
+    pub(crate) orbit_sizes: Vec<usize>,
+}
+
+impl<'a, T> SearchHooks<'a, T> for Canonical<'_, 'a, T> {
+    fn accept(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> bool {
+        self.inner.accept(matrix, selection)
+    }
+
+    fn solution(
+        &mut self,
+        matrix: &DancingLinksMatrix<'a, T>,
+        selection: &[MatrixCellRef<'a, T>],
+    ) -> SearchFlow {
+        let size = match self.group {
+            Some(group) => {
+                let rows = selection.iter().map(|row| row.row.into()).collect_vec();
+
+                match group.canonical_orbit(&rows) {
+                    Some(size) => size,
+                    None => return SearchFlow::Continue,
+                }
+            }
+            None => 1,
+        };
+
+        let collected = self.inner.solutions.len();
+        let flow = self.inner.solution(matrix, selection);
+
+        // The collector rejects the solutions outside the row bounds.
+        if self.inner.solutions.len() > collected {
+            self.orbit_sizes.push(size);
+        }
+
+        flow
+    }
+
+    fn stopped(&self) -> bool {
+        self.inner.stopped()
+    }
+}
@@ -0,0 +1,81 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    BruteForceSolver, ColumnSpec, IterativeAlgorithmXSolver, MatrixBuilder, MatrixRowBuilder,
+    Solution,
+    tests::utils::{BumpArena, dominoes_builder},
+};
+
+/// Builds a random matrix with 6 primary and 2 secondary columns.
+fn random_builder(seed: u64) -> MatrixRowBuilder<usize> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut builder = MatrixBuilder::from_iterable((1..=8).map(|c| {
+        if c <= 6 {
+            ColumnSpec::primary(c)
+        } else {
+            ColumnSpec::secondary(c)
+        }
+    }));
+
+    for _ in 0..rng.gen_range(5..16) {
+        let mut row = (1..=8).filter(|_| rng.gen_bool(0.3)).collect_vec();
+        if row.is_empty() {
+            row.push(rng.gen_range(1..=8));
+        }
+        builder = builder.add_row(row);
+    }
+
+    builder
+}
+
+fn row_sets(solutions: &[Solution<'_, usize>]) -> Vec<Vec<usize>> {
+    solutions
+        .iter()
+        .map(|solution| solution.solution_map.keys().copied().sorted().collect())
+        .sorted()
+        .collect()
+}
+
+#[test]
+fn test_brute_force() {
+    let builder = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6]);
+
+    let solutions = BruteForceSolver::new(&builder, false).solve();
+
+    assert_eq!(row_sets(&solutions), vec![vec![1, 2, 3], vec![4, 5]]);
+    assert_eq!(solutions[1].solution_map[&4], vec![&2, &3, &5]);
+}
+
+#[test]
+fn test_return_first() {
+    let builder = dominoes_builder(4, 4);
+
+    assert_eq!(BruteForceSolver::new(&builder, false).solve().len(), 36);
+    assert_eq!(BruteForceSolver::new(&builder, true).solve().len(), 1);
+}
+
+#[test]
+fn test_random_matrices() {
+    let mut total = 0;
+
+    for seed in 0..200 {
+        let builder = random_builder(seed);
+        let expected = row_sets(&BruteForceSolver::new(&builder, false).solve());
+
+        let arena: BumpArena = Bump::new().into();
+        let solver =
+            IterativeAlgorithmXSolver::new(random_builder(seed).build(&arena), true, false);
+
+        assert_eq!(row_sets(&solver.solve()), expected, "seed {seed}");
+        total += expected.len();
+    }
+
+    assert!(total > 0);
+}
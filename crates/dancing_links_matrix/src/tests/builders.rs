@@ -137,3 +137,27 @@ fn check_column<'a>(
     assert_eq!(cell.column().index, column.index);
     assert_eq!(column.cell().index, cell.index);
 }
+
+#[test]
+fn test_builder_row_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([3])
+        .with_weight(2.5)
+        .add_row([2, 3])
+        .with_weight(0.0)
+        .build(&arena);
+
+    assert_eq!(matrix.row_weight(1), 1.0);
+    assert_eq!(matrix.row_weight(2), 2.5);
+    assert_eq!(matrix.row_weight(3), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Row weight must be finite and non negative")]
+fn test_builder_negative_row_weight() {
+    MatrixBuilder::from_iterable([1, 2])
+        .add_row([1])
+        .with_weight(-1.0);
+}
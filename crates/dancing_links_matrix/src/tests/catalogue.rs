@@ -0,0 +1,72 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{Benchmark, IterativeAlgorithmXSolver, tests::utils::BumpArena};
+
+fn count(benchmark: Benchmark) -> u128 {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = benchmark.builder.build(&arena);
+
+    IterativeAlgorithmXSolver::new(matrix, true, false)
+        .solve()
+        .len() as u128
+}
+
+#[test]
+fn test_catalogue_names() {
+    let catalogue = Benchmark::catalogue();
+
+    assert_eq!(
+        catalogue.iter().map(|b| &b.name).unique().count(),
+        catalogue.len()
+    );
+}
+
+#[test]
+fn test_queens() {
+    for n in 1..=8 {
+        let benchmark = Benchmark::n_queens(n);
+        let expected = benchmark.solutions;
+
+        assert_eq!(count(benchmark), expected, "n = {n}");
+    }
+}
+
+#[test]
+fn test_langford() {
+    for n in 1..=8 {
+        let benchmark = Benchmark::langford(n);
+        let expected = benchmark.solutions;
+
+        assert_eq!(count(benchmark), expected, "n = {n}");
+    }
+}
+
+#[test]
+fn test_sudoku() {
+    assert_eq!(count(Benchmark::sudoku_4x4()), 288);
+}
+
+#[test]
+fn test_pentominoes() {
+    assert_eq!(Benchmark::pentominoes(3, 20).solutions, 8);
+    assert_eq!(Benchmark::pentominoes(20, 3).solutions, 8);
+
+    // Solving the boards takes too long for a debug build, so only check their number of
+    // placements, as given by Knuth in "Dancing Links".
+    let arena: BumpArena = Bump::new().into();
+    let matrix = Benchmark::pentominoes(6, 10).builder.build(&arena);
+    assert_eq!(matrix.column_count(), 12 + 60);
+    assert_eq!(matrix.row_count(), 2056);
+
+    let benchmark = Benchmark::pentominoes_with_hole();
+    assert_eq!(benchmark.solutions, 8 * benchmark.distinct.unwrap());
+    let matrix = benchmark.builder.build(&arena);
+    assert_eq!(matrix.row_count(), 1568);
+}
+
+#[test]
+#[should_panic(expected = "A 7x9 rectangle can't be tiled")]
+fn test_pentominoes_unknown_rectangle() {
+    Benchmark::pentominoes(7, 9);
+}
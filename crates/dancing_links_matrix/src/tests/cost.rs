@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+
+use crate::{MatrixBuilder, cost::MinCostSolver, tests::utils::BumpArena};
+
+#[test]
+fn solve_min_cost() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([1, 2])
+        .with_weight(5.0)
+        .add_row([3, 4])
+        .with_weight(5.0)
+        .add_row([1, 3])
+        .with_weight(1.0)
+        .add_row([2, 4])
+        .with_weight(2.0)
+        .add_row([1, 2, 3, 4])
+        .with_weight(4.0)
+        .build(&arena);
+
+    let solver = MinCostSolver::new(matrix);
+    let best = solver.solve().unwrap();
+
+    assert_eq!(best.cost, 3.0);
+    assert_eq!(keys(&best.solution.solution_map), HashSet::from([3, 4]));
+}
+
+#[test]
+fn solve_min_cost_default_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([1])
+        .add_row([2])
+        .add_row([3, 4])
+        .add_row([1, 2])
+        .build(&arena);
+
+    let solver = MinCostSolver::new(matrix);
+    let best = solver.solve().unwrap();
+
+    assert_eq!(best.cost, 2.0);
+    assert_eq!(keys(&best.solution.solution_map), HashSet::from([3, 4]));
+}
+
+#[test]
+fn solve_min_cost_improvements() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1])
+        .with_weight(1.0)
+        .add_row([2])
+        .with_weight(1.0)
+        .add_row([3])
+        .with_weight(1.0)
+        .add_row([1, 2])
+        .with_weight(1.5)
+        .add_row([1, 2, 3])
+        .with_weight(2.5)
+        .build(&arena);
+
+    let solver = MinCostSolver::new(matrix);
+
+    let mut costs = Vec::new();
+    let best = solver.solve_with(|s| costs.push(s.cost)).unwrap();
+
+    assert_eq!(best.cost, 2.5);
+    assert_eq!(costs.last(), Some(&2.5));
+    assert!(costs.windows(2).all(|w| w[1] < w[0]));
+}
+
+#[test]
+fn solve_min_cost_no_solution() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .build(&arena);
+
+    let solver = MinCostSolver::new(matrix);
+    assert!(solver.solve().is_none());
+}
+
+#[test]
+fn solve_min_cost_twice() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2])
+        .add_row([1])
+        .with_weight(3.0)
+        .add_row([2])
+        .add_row([1, 2])
+        .with_weight(3.5)
+        .build(&arena);
+
+    let solver = MinCostSolver::new(matrix);
+
+    assert_eq!(solver.solve().unwrap().cost, 3.5);
+    assert_eq!(solver.solve().unwrap().cost, 3.5);
+}
+
+fn keys<V>(map: &std::collections::HashMap<usize, V>) -> HashSet<usize> {
+    map.keys().copied().collect()
+}
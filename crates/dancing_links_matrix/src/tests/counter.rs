@@ -0,0 +1,54 @@
+use bumpalo::Bump;
+
+use crate::{
+    MatrixBuilder, MemoCounter,
+    tests::utils::{BumpArena, dominoes},
+};
+
+#[test]
+fn test_count_dominoes() {
+    let arena: BumpArena = Bump::new().into();
+    let (count, stats) = MemoCounter::new(dominoes(&arena, 8, 8)).count_with_stats();
+
+    assert_eq!(count, 12_988_816);
+    assert!(stats.hits > 0);
+    assert_eq!(stats.evictions, 0);
+}
+
+#[test]
+fn test_memory_limit() {
+    let arena: BumpArena = Bump::new().into();
+    let unlimited = MemoCounter::new(dominoes(&arena, 4, 8))
+        .count_with_stats()
+        .1;
+
+    let arena: BumpArena = Bump::new().into();
+    let limit = unlimited.peak_bytes / 4;
+    let (count, stats) = MemoCounter::new(dominoes(&arena, 4, 8))
+        .with_memory_limit(limit)
+        .count_with_stats();
+
+    assert_eq!(count, 2245);
+    assert!(stats.evictions > 0);
+    assert!(stats.peak_bytes <= limit);
+    assert!(stats.misses > unlimited.misses);
+}
+
+#[test]
+fn test_no_cache() {
+    let arena: BumpArena = Bump::new().into();
+    let counter = MemoCounter::new(dominoes(&arena, 3, 6)).with_memory_limit(0);
+
+    assert_eq!(counter.count(), 41);
+}
+
+#[test]
+fn test_count_no_solution() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .build(&arena);
+
+    assert_eq!(MemoCounter::new(matrix).count(), 0);
+}
@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    DiverseSolver, IterativeAlgorithmXSolver, Solution,
+    tests::utils::{BumpArena, dominoes},
+};
+
+fn rows(solution: &Solution<'_, usize>) -> HashSet<usize> {
+    solution.solution_map.keys().copied().collect()
+}
+
+fn distance(a: &Solution<'_, usize>, b: &Solution<'_, usize>) -> usize {
+    rows(a).symmetric_difference(&rows(b)).count()
+}
+
+#[test]
+fn test_min_distance() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = DiverseSolver::new(dominoes(&arena, 4, 4));
+
+    let solutions = solver.solve_min_distance(5, 6);
+    assert_eq!(solutions.len(), 5);
+
+    for (a, b) in solutions.iter().tuple_combinations() {
+        assert!(distance(a, b) >= 6);
+    }
+}
+
+#[test]
+fn test_min_distance_too_large() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = DiverseSolver::new(dominoes(&arena, 2, 4));
+
+    // A 2x4 board is tiled with 4 dominoes, so two tilings differ by at most 8 rows.
+    let solutions = solver.solve_min_distance(3, 9);
+    assert_eq!(solutions.len(), 1);
+}
+
+#[test]
+fn test_max_diverse() {
+    let arena: BumpArena = Bump::new().into();
+    let all = IterativeAlgorithmXSolver::new(dominoes(&arena, 3, 4), true, false).solve();
+
+    let arena: BumpArena = Bump::new().into();
+    let solutions = DiverseSolver::new(dominoes(&arena, 3, 4)).solve_max_diverse(2);
+    assert_eq!(solutions.len(), 2);
+
+    let farthest = all
+        .iter()
+        .map(|solution| distance(solution, &solutions[0]))
+        .max()
+        .unwrap();
+    assert_eq!(distance(&solutions[1], &solutions[0]), farthest);
+}
+
+#[test]
+fn test_max_diverse_all_solutions() {
+    let arena: BumpArena = Bump::new().into();
+    let solutions = DiverseSolver::new(dominoes(&arena, 2, 4)).solve_max_diverse(10);
+
+    // All the 5 tilings are returned, each one once.
+    assert_eq!(solutions.len(), 5);
+    assert_eq!(
+        solutions
+            .iter()
+            .map(|s| rows(s).into_iter().sorted().collect_vec())
+            .unique()
+            .count(),
+        5
+    );
+}
+
+#[test]
+fn test_forbidden_rows() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = DiverseSolver::new(dominoes(&arena, 2, 4)).with_forbidden_rows([2]);
+
+    // Row 2 is the vertical domino on the first column, the 2 other tilings start with
+    // two horizontal dominoes.
+    let solutions = solver.solve_max_diverse(10);
+    assert_eq!(solutions.len(), 2);
+    assert!(solutions.iter().all(|s| !rows(s).contains(&2)));
+}
+
+#[test]
+fn test_forbidden_rows_twice() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = DiverseSolver::new(dominoes(&arena, 2, 4)).with_forbidden_rows([2, 2]);
+
+    // Hiding row 2 twice would decrease the sizes of its columns twice.
+    let solutions = solver.solve_max_diverse(10);
+    assert_eq!(solutions.len(), 2);
+    assert!(solutions.iter().all(|s| !rows(s).contains(&2)));
+}
+
+#[test]
+#[should_panic(expected = "Row 100 does not exist")]
+fn test_forbidden_rows_unknown() {
+    let arena: BumpArena = Bump::new().into();
+    DiverseSolver::new(dominoes(&arena, 2, 4)).with_forbidden_rows([100]);
+}
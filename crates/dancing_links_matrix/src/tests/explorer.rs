@@ -0,0 +1,110 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    ChooseError, Explorer, IterativeAlgorithmXSolver, MatrixBuilder,
+    tests::utils::{BumpArena, dominoes},
+};
+
+/// Counts the exact covers with a depth-first search using only the explorer.
+fn count(explorer: &mut Explorer<'_, '_, usize>) -> usize {
+    let Some(column) = explorer.min_column() else {
+        return 1;
+    };
+
+    let mut total = 0;
+    for row in explorer.column_rows(column).collect_vec() {
+        explorer.choose(row).unwrap();
+        total += count(explorer);
+        explorer.unchoose(row).unwrap();
+    }
+
+    total
+}
+
+#[test]
+fn test_custom_search() {
+    let arena: BumpArena = Bump::new().into();
+    let mut matrix = dominoes(&arena, 4, 4);
+
+    assert_eq!(count(&mut matrix.explorer()), 36);
+    assert_eq!(
+        IterativeAlgorithmXSolver::new(matrix, true, false)
+            .solve()
+            .len(),
+        36
+    );
+}
+
+#[test]
+fn test_queries() {
+    let arena: BumpArena = Bump::new().into();
+    let mut matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3])
+        .build(&arena);
+    let mut explorer = matrix.explorer();
+
+    assert_eq!(explorer.active_columns().collect_vec(), vec![1, 2, 3]);
+    assert_eq!(explorer.column_size(2), 2);
+    assert_eq!(explorer.row_columns(2).collect_vec(), vec![2, 3]);
+    assert_eq!(explorer.min_column(), Some(1));
+
+    explorer.choose(1).unwrap();
+    assert_eq!(explorer.active_columns().collect_vec(), vec![3]);
+    assert_eq!(explorer.column_size(3), 1);
+    assert_eq!(explorer.column_rows(3).collect_vec(), vec![3]);
+    assert!(!explorer.is_row_active(2));
+    assert!(!explorer.is_solved());
+
+    assert_eq!(explorer.choose(2), Err(ChooseError::Conflict(2)));
+    assert_eq!(explorer.choose(4), Err(ChooseError::UnknownRow(4)));
+
+    explorer.choose(3).unwrap();
+    assert!(explorer.is_solved());
+    assert_eq!(explorer.min_column(), None);
+    assert_eq!(explorer.chosen(), vec![1, 3]);
+
+    assert_eq!(explorer.undo(), Some(3));
+    assert_eq!(explorer.undo(), Some(1));
+    assert_eq!(explorer.undo(), None);
+    assert_eq!(explorer.column_size(2), 2);
+}
+
+#[test]
+fn test_drop_restores() {
+    let arena: BumpArena = Bump::new().into();
+    let mut matrix = dominoes(&arena, 2, 4);
+
+    let mut explorer = matrix.explorer();
+    explorer.choose(1).unwrap();
+    explorer.choose(5).unwrap();
+    drop(explorer);
+
+    assert_eq!(
+        IterativeAlgorithmXSolver::new(matrix, true, false)
+            .solve()
+            .len(),
+        5
+    );
+}
+
+#[test]
+fn test_wrong_undo_order() {
+    let arena: BumpArena = Bump::new().into();
+    let mut matrix = dominoes(&arena, 2, 4);
+    let mut explorer = matrix.explorer();
+
+    explorer.choose(1).unwrap();
+    explorer.choose(6).unwrap();
+    assert_eq!(explorer.unchoose(1), Err(ChooseError::NotLastChosen(1)));
+    assert_eq!(explorer.chosen(), [1, 6]);
+
+    assert_eq!(explorer.unchoose(6), Ok(()));
+    assert_eq!(explorer.chosen(), [1]);
+    assert_eq!(
+        explorer.unchoose(6).unwrap_err().to_string(),
+        "row 6 is not the last row chosen"
+    );
+}
@@ -0,0 +1,81 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    BruteForceSolver, InstanceGenerator, IterativeAlgorithmXSolver, tests::utils::BumpArena, verify,
+};
+
+#[test]
+fn test_planted_solutions_found() {
+    for seed in 0..20 {
+        let instance = InstanceGenerator::new(30)
+            .with_secondary(5)
+            .with_density(0.2)
+            .with_random_rows(40)
+            .with_planted(3)
+            .with_seed(seed)
+            .generate();
+
+        let arena: BumpArena = Bump::new().into();
+        let matrix = instance.builder.build(&arena);
+
+        assert_eq!(instance.planted.len(), 3);
+        for rows in &instance.planted {
+            assert!(verify(&matrix, rows.iter().copied()).is_valid());
+        }
+
+        let found = IterativeAlgorithmXSolver::new(matrix, true, false)
+            .solve()
+            .into_iter()
+            .map(|solution| solution.solution_map.into_keys().sorted().collect_vec())
+            .collect_vec();
+
+        for rows in &instance.planted {
+            assert!(found.contains(rows), "seed {seed}: {rows:?} not found");
+        }
+    }
+}
+
+#[test]
+fn test_planted_disjoint() {
+    let instance = InstanceGenerator::new(20).with_planted(4).generate();
+
+    let rows = instance.planted.concat();
+    assert_eq!(rows.iter().unique().count(), rows.len());
+}
+
+#[test]
+fn test_seed() {
+    let generator = InstanceGenerator::new(12).with_secondary(3).with_seed(7);
+
+    let first = generator.generate();
+    let second = generator.generate();
+    let other = generator.clone().with_seed(8).generate();
+
+    assert_eq!(first.builder.row_lists(), second.builder.row_lists());
+    assert_eq!(first.planted, second.planted);
+    assert_ne!(first.builder.row_lists(), other.builder.row_lists());
+}
+
+#[test]
+fn test_matches_brute_force() {
+    for seed in 0..50 {
+        let instance = InstanceGenerator::new(10)
+            .with_secondary(2)
+            .with_density(0.4)
+            .with_random_rows(8)
+            .with_planted(2)
+            .with_seed(seed)
+            .generate();
+
+        let solutions = BruteForceSolver::new(&instance.builder, false).solve();
+        let found = solutions
+            .iter()
+            .map(|solution| solution.solution_map.keys().copied().sorted().collect_vec())
+            .collect_vec();
+
+        for rows in &instance.planted {
+            assert!(found.contains(rows), "seed {seed}: {rows:?} not found");
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use bumpalo::Bump;
+
+use crate::{
+    ColumnSpec, ImportError, IterativeAlgorithmXSolver, MatrixBuilder, tests::utils::BumpArena,
+};
+
+/// The rows of the matrix used in the solver tests, with columns numbered from 0.
+const ROWS: [&[usize]; 5] = [&[0, 1], &[2, 3], &[4, 5], &[1, 2, 4], &[0, 3, 5]];
+
+#[test]
+fn test_same_rows() {
+    let expected = ROWS
+        .iter()
+        .fold(MatrixBuilder::from_iterable(1..=6), |builder, row| {
+            builder.add_row_index(row.iter().map(|column| column + 1))
+        })
+        .row_lists();
+
+    let dense = ROWS
+        .iter()
+        .map(|row| (0..6).map(|column| row.contains(&column)).collect())
+        .collect::<Vec<_>>();
+    let builder = MatrixBuilder::from_dense(1..=6, &dense).unwrap();
+    assert_eq!(builder.row_lists(), expected);
+
+    let indptr = [0, 2, 4, 6, 9, 12];
+    let indices = [1, 0, 2, 3, 5, 4, 4, 2, 1, 0, 3, 5];
+    let builder = MatrixBuilder::from_csr(1..=6, &indptr, &indices).unwrap();
+    assert_eq!(builder.row_lists(), expected);
+
+    let pairs = ROWS
+        .iter()
+        .enumerate()
+        .flat_map(|(row, columns)| columns.iter().map(move |&column| (row, column)))
+        .rev();
+    let builder = MatrixBuilder::from_pairs(1..=6, pairs).unwrap();
+    assert_eq!(builder.row_lists(), expected);
+
+    let arena: BumpArena = Bump::new().into();
+    let solver = IterativeAlgorithmXSolver::new(builder.build(&arena), true, false);
+    assert_eq!(solver.solve().len(), 2);
+}
+
+#[test]
+fn test_secondary_columns() {
+    let columns = [ColumnSpec::primary("a"), ColumnSpec::secondary("x")];
+    let builder = MatrixBuilder::from_dense::<&str>(columns, &[vec![true, true]]).unwrap();
+
+    let arena: BumpArena = Bump::new().into();
+    let matrix = builder.build(&arena);
+    assert!(!matrix.column(2).unwrap().is_primary());
+}
+
+#[test]
+fn test_dense_errors() {
+    let result = MatrixBuilder::from_dense(1..=3, &[vec![true, false, false], vec![true]]);
+    assert_eq!(
+        result.err(),
+        Some(ImportError::RowLength { row: 1, len: 1 })
+    );
+
+    let result = MatrixBuilder::from_dense(1..=2, &[vec![false, false]]);
+    assert_eq!(result.err(), Some(ImportError::EmptyRow { row: 0 }));
+}
+
+#[test]
+fn test_csr_errors() {
+    let result = MatrixBuilder::from_csr(1..=3, &[1, 2], &[0, 1]);
+    assert_eq!(result.err(), Some(ImportError::InvalidIndptr));
+
+    let result = MatrixBuilder::from_csr(1..=3, &[0, 2, 1], &[0]);
+    assert_eq!(result.err(), Some(ImportError::InvalidIndptr));
+
+    let result = MatrixBuilder::from_csr(1..=3, &[0, 1, 3], &[0, 2, 3]);
+    assert_eq!(
+        result.err(),
+        Some(ImportError::ColumnOutOfRange { row: 1, column: 3 })
+    );
+
+    let result = MatrixBuilder::from_csr(1..=3, &[0, 2], &[1, 1]);
+    assert_eq!(
+        result.err(),
+        Some(ImportError::DuplicateEntry { row: 0, column: 1 })
+    );
+}
+
+#[test]
+fn test_pairs_errors() {
+    let result = MatrixBuilder::from_pairs(1..=3, [(0, 0), (2, 1)]);
+    let error = result.err().unwrap();
+
+    assert_eq!(error, ImportError::EmptyRow { row: 1 });
+    assert_eq!(error.to_string(), "row 1 has no column");
+}
+
+#[test]
+fn test_pairs_huge_row() {
+    let result = MatrixBuilder::from_pairs(1..=3, [(usize::MAX, 0)]);
+    assert_eq!(result.err(), Some(ImportError::EmptyRow { row: 0 }));
+
+    let result = MatrixBuilder::from_pairs(1..=3, [(0, 0), (usize::MAX, 1), (1, 2)]);
+    assert_eq!(result.err(), Some(ImportError::EmptyRow { row: 2 }));
+}
+
+#[test]
+fn test_no_columns() {
+    let columns: [usize; 0] = [];
+
+    let result = MatrixBuilder::from_dense(columns, &[]);
+    assert_eq!(result.err(), Some(ImportError::NoColumns));
+
+    let result = MatrixBuilder::from_csr(columns, &[0], &[]);
+    assert_eq!(result.err(), Some(ImportError::NoColumns));
+
+    let result = MatrixBuilder::from_pairs(columns, []);
+    let error = result.err().unwrap();
+
+    assert_eq!(error, ImportError::NoColumns);
+    assert_eq!(error.to_string(), "there is no column");
+}
@@ -0,0 +1,92 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    BruteForceSolver, ColumnSpec, IterativeAlgorithmXSolver, LexicographicSolver, MatrixBuilder,
+    tests::utils::{BumpArena, dominoes, rows},
+};
+
+#[test]
+fn test_lexicographic_order() {
+    let arena: BumpArena = Bump::new().into();
+    let expected = IterativeAlgorithmXSolver::new(dominoes(&arena, 3, 4), true, false)
+        .solve()
+        .iter()
+        .map(rows)
+        .sorted()
+        .collect_vec();
+
+    let arena: BumpArena = Bump::new().into();
+    let solver = LexicographicSolver::new(dominoes(&arena, 3, 4));
+
+    assert_eq!(solver.count(), 11);
+    assert_eq!(solver.solve().iter().map(rows).collect_vec(), expected);
+}
+
+#[test]
+fn test_solve_range() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = LexicographicSolver::new(dominoes(&arena, 4, 4));
+    let all = solver.solve().iter().map(rows).collect_vec();
+
+    assert_eq!(all.len(), 36);
+    assert_eq!(
+        solver.solve_range(10..20).iter().map(rows).collect_vec(),
+        all[10..20]
+    );
+    assert_eq!(
+        solver.solve_range(30..40).iter().map(rows).collect_vec(),
+        all[30..]
+    );
+    assert!(solver.solve_range(36..40).is_empty());
+    assert!(solver.solve_range(5..5).is_empty());
+}
+
+#[test]
+fn test_secondary_columns() {
+    let columns = [
+        ColumnSpec::primary(1),
+        ColumnSpec::primary(2),
+        ColumnSpec::secondary(3),
+    ];
+    let builder = MatrixBuilder::from_iterable(columns)
+        .add_row([3])
+        .add_row([1])
+        .add_row([1, 3])
+        .add_row([2, 3])
+        .add_row([2]);
+
+    // Row 1 has no primary column, so it is in no solution.
+    let expected = BruteForceSolver::new(&builder, false)
+        .solve()
+        .iter()
+        .map(rows)
+        .collect_vec();
+    assert_eq!(expected, [vec![2, 4], vec![2, 5], vec![3, 5]]);
+
+    let arena: BumpArena = Bump::new().into();
+    let solver = LexicographicSolver::new(builder.build(&arena));
+
+    assert_eq!(solver.count(), 3);
+    assert_eq!(solver.solve().iter().map(rows).collect_vec(), expected);
+    assert_eq!(rows(&solver.nth(2).unwrap()), vec![3, 5]);
+}
+
+#[test]
+fn test_nth() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([3, 4])
+        .add_row([1, 2])
+        .add_row([1, 3])
+        .add_row([2, 4])
+        .add_row([1, 2, 3, 4])
+        .build(&arena);
+    let solver = LexicographicSolver::new(matrix);
+
+    assert_eq!(rows(&solver.nth(0).unwrap()), vec![1, 2]);
+    assert_eq!(rows(&solver.nth(1).unwrap()), vec![3, 4]);
+    assert_eq!(rows(&solver.nth(2).unwrap()), vec![5]);
+    assert!(solver.nth(3).is_none());
+    assert!(solver.nth(usize::MAX).is_none());
+}
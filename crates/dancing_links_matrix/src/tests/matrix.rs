@@ -233,6 +233,89 @@ fn test_cell_iterator_down(include_start: bool) {
     );
 }
 
+#[test]
+fn test_row_cell() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = build_matrix(&arena);
+
+    assert_eq!(matrix.row_cell(1).unwrap().index, 4);
+    assert_eq!(matrix.row_cell(2).unwrap().index, 6);
+    assert_eq!(matrix.row_cell(4).unwrap().index, 10);
+    assert!(matrix.row_cell(0).is_none());
+    assert!(matrix.row_cell(5).is_none());
+
+    let solution = matrix.solution_from_rows([1]);
+    assert_eq!(
+        solution.solution_map[&1].iter().sorted().collect_vec(),
+        [&"1", &"2"]
+    );
+}
+
+#[test]
+fn test_shuffle_rows() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = build_matrix(&arena);
+    let column_rows = |m: &DancingLinksMatrix<'_, String>| {
+        m.columns
+            .iter()
+            .skip(1)
+            .map(|c| {
+                m.iterate_cells(c.cell(), CellIteratorDir::Down, false)
+                    .map(|cell| cell.row)
+                    .collect_vec()
+            })
+            .collect_vec()
+    };
+
+    let before = column_rows(&matrix);
+    matrix.reseed(1);
+
+    for _ in 0..5 {
+        matrix.shuffle_rows();
+        let after = column_rows(&matrix);
+
+        for (b, a) in before.iter().zip(&after) {
+            assert_eq!(
+                b.iter().map(|r| r.to_string()).sorted().collect_vec(),
+                a.iter().map(|r| r.to_string()).sorted().collect_vec()
+            );
+        }
+
+        for cell in matrix.cells.iter() {
+            assert!(std::ptr::eq(cell.down().up(), *cell));
+        }
+    }
+}
+
+#[test]
+fn test_cover_uncover_flag() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = build_matrix(&arena);
+    let column = locate_column(&matrix, "1").unwrap();
+
+    assert!(!column.covered());
+    matrix.cover(column);
+    assert!(column.covered());
+    matrix.uncover(column);
+    assert!(!column.covered());
+}
+
+#[test]
+fn test_hide_unhide_row() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = build_matrix(&arena);
+    let row = locate_cell(&matrix, 4, "1").unwrap();
+    let sizes = |m: &DancingLinksMatrix<String>| m.columns.iter().map(|c| c.size()).collect_vec();
+
+    assert_eq!(sizes(&matrix), [0, 3, 3, 3]);
+    matrix.hide_row(row);
+    assert_eq!(sizes(&matrix), [0, 2, 2, 2]);
+    assert_eq!(find_cell(&matrix, 4, "2"), None);
+    matrix.unhide_row(row);
+    assert_eq!(sizes(&matrix), [0, 3, 3, 3]);
+    assert_eq!(find_cell(&matrix, 4, "2"), Some(11));
+}
+
 fn build_matrix(arena: &impl Arena) -> DancingLinksMatrix<'_, String> {
     MatrixBuilder
         .add_column(1.to_string())
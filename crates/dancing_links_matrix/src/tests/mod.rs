@@ -1,6 +1,30 @@
+mod brute;
 mod builders;
+mod catalogue;
 mod cells;
+mod cost;
+mod counter;
+mod diverse;
+mod explorer;
+mod generator;
+mod import;
+mod lexicographic;
 mod matrix;
+mod nogood;
+mod partial;
+mod prune;
+mod restarts;
+mod sampler;
+mod semiring;
+mod set_cover;
 mod solver;
+mod split;
+mod stats;
+mod stepper;
+mod stream;
+mod symmetry;
+mod verify;
+mod view;
+mod zdd;
 
 mod utils;
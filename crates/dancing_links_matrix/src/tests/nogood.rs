@@ -0,0 +1,97 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    Arena, DancingLinksMatrix, IterativeAlgorithmXSolver, MatrixBuilder, NogoodSolver,
+    tests::utils::{BumpArena, dominoes, rows},
+};
+
+#[test]
+fn test_same_solutions() {
+    let arena: BumpArena = Bump::new().into();
+    let expected = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 5), true, false)
+        .solve()
+        .iter()
+        .map(rows)
+        .sorted()
+        .collect_vec();
+
+    let arena: BumpArena = Bump::new().into();
+    let (solutions, stats) = NogoodSolver::new(dominoes(&arena, 4, 5), false).solve_with_stats();
+
+    assert_eq!(solutions.iter().map(rows).sorted().collect_vec(), expected);
+    assert!(stats.nodes > 0);
+}
+
+/// Builds the matrix of the domino tilings of a `n` x `n` board with two opposite corners
+/// removed, which has no tiling.
+fn mutilated(arena: &impl Arena, n: usize) -> DancingLinksMatrix<'_, usize> {
+    let removed = [0, n * n - 1];
+    let cells = (0..n * n).filter(|cell| !removed.contains(cell));
+    let mut builder = MatrixBuilder::from_iterable(cells);
+
+    for (r, c) in (0..n).cartesian_product(0..n) {
+        let cell = r * n + c;
+        if removed.contains(&cell) {
+            continue;
+        }
+        if c + 1 < n && !removed.contains(&(cell + 1)) {
+            builder = builder.add_row([cell, cell + 1]);
+        }
+        if r + 1 < n && !removed.contains(&(cell + n)) {
+            builder = builder.add_row([cell, cell + n]);
+        }
+    }
+
+    builder.build(arena)
+}
+
+#[test]
+fn test_no_solution() {
+    let arena: BumpArena = Bump::new().into();
+    let (solutions, stats) = NogoodSolver::new(mutilated(&arena, 4), false).solve_with_stats();
+
+    assert!(solutions.is_empty());
+    assert!(stats.conflicts > 0);
+    assert!(stats.learned > 0);
+    assert_eq!(stats.discarded, 0);
+}
+
+#[test]
+fn test_pruning() {
+    let arena: BumpArena = Bump::new().into();
+    let (_, learning) = NogoodSolver::new(mutilated(&arena, 6), false).solve_with_stats();
+
+    let arena: BumpArena = Bump::new().into();
+    let (_, plain) = NogoodSolver::new(mutilated(&arena, 6), false)
+        .with_memory_limit(0)
+        .solve_with_stats();
+
+    assert!(learning.pruned + learning.backjumps > 0);
+    assert!(learning.nodes < plain.nodes);
+}
+
+#[test]
+fn test_memory_cap() {
+    let arena: BumpArena = Bump::new().into();
+    let (_, unlimited) = NogoodSolver::new(mutilated(&arena, 4), false).solve_with_stats();
+
+    let arena: BumpArena = Bump::new().into();
+    let limit = unlimited.bytes / 4;
+    let (solutions, stats) = NogoodSolver::new(mutilated(&arena, 4), false)
+        .with_memory_limit(limit)
+        .solve_with_stats();
+
+    assert!(solutions.is_empty());
+    assert!(stats.learned > 0);
+    assert!(stats.discarded > 0);
+    assert!(stats.bytes <= limit);
+}
+
+#[test]
+fn test_return_first() {
+    let arena: BumpArena = Bump::new().into();
+    let solutions = NogoodSolver::new(dominoes(&arena, 4, 5), true).solve();
+
+    assert_eq!(solutions.len(), 1);
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+
+use crate::{MatrixBuilder, MaxPartialCoverSolver, tests::utils::BumpArena};
+
+#[test]
+fn solve_partial_cover() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3, 4])
+        .add_row([4, 5])
+        .add_row([1, 5])
+        .build(&arena);
+
+    let solver = MaxPartialCoverSolver::new(matrix);
+    let cover = solver.solve();
+
+    assert!(!cover.is_exact());
+    assert_eq!(cover.covered_weight, 4.0);
+    assert_eq!(cover.solution.solution_map.len(), 2);
+    assert_eq!(cover.uncovered.len(), 1);
+
+    let covered: HashSet<_> = cover
+        .solution
+        .solution_map
+        .values()
+        .flatten()
+        .copied()
+        .collect();
+    assert!(!covered.contains(cover.uncovered[0]));
+}
+
+#[test]
+fn solve_partial_cover_weighted() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3, 4])
+        .add_row([4, 5])
+        .add_row([1, 5])
+        .build(&arena);
+
+    let solver = MaxPartialCoverSolver::new(matrix);
+    let cover = solver.solve_weighted(|c| match c {
+        2 => 10.0,
+        4 => 5.0,
+        5 => 0.5,
+        _ => 1.0,
+    });
+
+    assert_eq!(cover.covered_weight, 17.0);
+    assert_eq!(keys(&cover), HashSet::from([1, 3]));
+    assert_eq!(cover.uncovered, [&5]);
+}
+
+#[test]
+#[should_panic(expected = "Column weight must be finite and non negative, got -1")]
+fn solve_partial_cover_negative_weight() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .build(&arena);
+
+    MaxPartialCoverSolver::new(matrix).solve_weighted(|&c| if c == 3 { -1.0 } else { 1.0 });
+}
+
+#[test]
+fn solve_partial_cover_exact() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([1, 2])
+        .add_row([3])
+        .add_row([3, 4])
+        .add_row([2, 3, 4])
+        .build(&arena);
+
+    let solver = MaxPartialCoverSolver::new(matrix);
+    let cover = solver.solve();
+
+    assert!(cover.is_exact());
+    assert_eq!(cover.covered_weight, 4.0);
+    assert_eq!(keys(&cover), HashSet::from([1, 3]));
+}
+
+#[test]
+fn solve_partial_cover_no_rows() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2]).build(&arena);
+
+    let solver = MaxPartialCoverSolver::new(matrix);
+    let cover = solver.solve();
+
+    assert_eq!(cover.covered_weight, 0.0);
+    assert!(cover.solution.solution_map.is_empty());
+    assert_eq!(
+        HashSet::<_>::from_iter(cover.uncovered),
+        HashSet::from([&1, &2])
+    );
+}
+
+fn keys<T>(cover: &crate::PartialCover<'_, T>) -> HashSet<usize> {
+    cover.solution.solution_map.keys().copied().collect()
+}
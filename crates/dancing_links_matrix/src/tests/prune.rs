@@ -0,0 +1,91 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    IterativeAlgorithmXSolver, MatrixBuilder, PartialSelection, Pruner, Solution,
+    tests::utils::{BumpArena, dominoes},
+};
+
+fn has_vertical(items: impl Iterator<Item = usize>, cols: usize) -> bool {
+    let (a, b) = items.collect_tuple().unwrap();
+    a.abs_diff(b) == cols
+}
+
+#[test]
+fn test_pruner_closure() {
+    let arena: BumpArena = Bump::new().into();
+    let expected = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), true, false)
+        .solve()
+        .into_iter()
+        .filter(|solution: &Solution<'_, usize>| {
+            solution
+                .solution_map
+                .values()
+                .filter(|items| items[0].abs_diff(*items[1]) == 4)
+                .count()
+                <= 2
+        })
+        .count();
+
+    // At most 2 vertical dominoes.
+    let arena: BumpArena = Bump::new().into();
+    let solver = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), true, false).with_pruner(
+        |selection: &PartialSelection<'_, '_, usize>| {
+            (0..selection.depth())
+                .filter(|&i| has_vertical(selection.row_items(i).copied(), 4))
+                .count()
+                <= 2
+        },
+    );
+
+    assert_eq!(solver.solve().len(), expected);
+    assert!(expected > 0 && expected < 36);
+}
+
+struct MaxSum {
+    max: usize,
+}
+
+impl Pruner<usize> for MaxSum {
+    fn accept(&mut self, selection: &PartialSelection<'_, '_, usize>) -> bool {
+        selection.last_row_items().sum::<usize>() <= self.max
+    }
+}
+
+#[test]
+fn test_pruner_trait() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([1, 4])
+        .add_row([2, 3])
+        .add_row([1])
+        .add_row([2])
+        .build(&arena);
+
+    // The covers using the row [3, 4] are pruned, only [1, 4] and [2, 3] remain.
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_pruner(MaxSum { max: 5 });
+
+    let solutions = solver.solve();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(
+        solutions[0]
+            .solution_map
+            .keys()
+            .copied()
+            .sorted()
+            .collect_vec(),
+        vec![3, 4]
+    );
+}
+
+#[test]
+fn test_pruner_depth() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = IterativeAlgorithmXSolver::new(dominoes(&arena, 2, 4), true, false)
+        .with_pruner(|selection: &PartialSelection<'_, '_, usize>| selection.depth() < 4);
+
+    assert!(solver.solve().is_empty());
+    assert!(solver.solve_min_rows().is_none());
+}
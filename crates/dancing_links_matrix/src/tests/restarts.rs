@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    Arena, DancingLinksMatrix, IterativeAlgorithmXSolver, MatrixBuilder, RestartStrategy,
+    tests::utils::{BumpArena, rows},
+};
+
+#[test]
+fn test_luby_limits() {
+    let strategy = RestartStrategy::Luby { unit: 10 };
+    let limits = (0..15).map(|i| strategy.node_limit(i)).collect_vec();
+
+    assert_eq!(
+        limits,
+        [10, 10, 20, 10, 10, 20, 40, 10, 10, 20, 10, 10, 20, 40, 80]
+    );
+}
+
+#[test]
+fn test_geometric_limits() {
+    let strategy = RestartStrategy::Geometric {
+        initial: 4,
+        factor: 1.5,
+    };
+    let limits = (0..5).map(|i| strategy.node_limit(i)).collect_vec();
+
+    assert_eq!(limits, [4, 6, 9, 13, 20]);
+    assert_eq!(strategy.node_limit(1000), usize::MAX);
+}
+
+#[test]
+fn test_restarts_first_solution() {
+    let arena: BumpArena = Bump::new().into();
+
+    for seed in 0..10 {
+        let solver = IterativeAlgorithmXSolver::new(build_matrix(&arena), true, true)
+            .with_seed(seed)
+            .with_restarts(RestartStrategy::Luby { unit: 1 });
+        let first = solver.solve();
+        assert_eq!(first.len(), 1);
+
+        let solver = IterativeAlgorithmXSolver::new(build_matrix(&arena), true, true)
+            .with_seed(seed)
+            .with_restarts(RestartStrategy::Luby { unit: 1 });
+        assert_eq!(rows(&first[0]), rows(&solver.solve()[0]));
+    }
+}
+
+#[test]
+fn test_restarts_all_solutions() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = IterativeAlgorithmXSolver::new(build_matrix(&arena), true, false)
+        .with_seed(5)
+        .with_restarts(RestartStrategy::Geometric {
+            initial: 1,
+            factor: 2.0,
+        });
+
+    let solutions: HashSet<_> = solver.solve().iter().map(rows).collect();
+    assert_eq!(
+        solutions,
+        HashSet::from([vec![1, 2, 3], vec![4, 5], vec![6, 7]])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Restart factor must be finite and greater than 1, got 1")]
+fn test_restarts_factor_one() {
+    let arena: BumpArena = Bump::new().into();
+    IterativeAlgorithmXSolver::new(build_matrix(&arena), true, true).with_restarts(
+        RestartStrategy::Geometric {
+            initial: 1,
+            factor: 1.0,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Restart factor must be finite and greater than 1, got NaN")]
+fn test_restarts_factor_nan() {
+    let arena: BumpArena = Bump::new().into();
+    IterativeAlgorithmXSolver::new(build_matrix(&arena), true, true).with_restarts(
+        RestartStrategy::Geometric {
+            initial: 1,
+            factor: f64::NAN,
+        },
+    );
+}
+
+#[test]
+fn test_random_column_seed() {
+    let arena: BumpArena = Bump::new().into();
+
+    let solve = |seed| {
+        IterativeAlgorithmXSolver::new(build_matrix(&arena), false, false)
+            .with_seed(seed)
+            .solve()
+            .iter()
+            .map(rows)
+            .collect_vec()
+    };
+
+    for seed in 0..10 {
+        let solutions = solve(seed);
+        assert_eq!(solutions.len(), 3);
+        assert_eq!(solutions, solve(seed));
+    }
+}
+
+fn build_matrix(arena: &impl Arena) -> DancingLinksMatrix<'_, usize> {
+    MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6])
+        .add_row([1, 3, 5])
+        .add_row([2, 4, 6])
+        .build(arena)
+}
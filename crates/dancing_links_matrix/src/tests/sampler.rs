@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    Arena, DancingLinksMatrix, MatrixBuilder, RandomSampler,
+    tests::utils::{BumpArena, rows},
+};
+
+#[test]
+fn test_sample() {
+    let arena: BumpArena = Bump::new().into();
+    let mut sampler = RandomSampler::new(build_matrix(&arena), 7);
+
+    let mut seen = HashSet::new();
+    for _ in 0..200 {
+        let solution = sampler.sample().unwrap();
+        seen.insert(rows(&solution));
+    }
+
+    assert_eq!(seen, HashSet::from([vec![1, 2, 3], vec![4, 5], vec![6, 7]]));
+}
+
+#[test]
+fn test_sample_seed() {
+    let arena: BumpArena = Bump::new().into();
+    let mut first = RandomSampler::new(build_matrix(&arena), 42);
+    let mut second = RandomSampler::new(build_matrix(&arena), 42);
+
+    for _ in 0..20 {
+        assert_eq!(
+            rows(&first.sample().unwrap()),
+            rows(&second.sample().unwrap())
+        );
+        assert_eq!(
+            rows(&first.sample_uniform().unwrap()),
+            rows(&second.sample_uniform().unwrap())
+        );
+    }
+}
+
+#[test]
+fn test_sample_node_limit() {
+    let arena: BumpArena = Bump::new().into();
+    let mut sampler = RandomSampler::new(build_matrix(&arena), 3).with_node_limit(1);
+
+    for _ in 0..20 {
+        assert!(sampler.sample().is_some());
+    }
+}
+
+#[test]
+fn test_sample_no_solution() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .build(&arena);
+
+    let mut sampler = RandomSampler::new(matrix, 0).with_node_limit(1);
+    assert!(sampler.sample().is_none());
+    assert!(sampler.sample_uniform().is_none());
+}
+
+#[test]
+fn test_sample_uniform() {
+    let arena: BumpArena = Bump::new().into();
+    let mut sampler = RandomSampler::new(build_matrix(&arena), 11);
+
+    let counts = (0..3000)
+        .map(|_| rows(&sampler.sample_uniform().unwrap()))
+        .counts();
+
+    assert_eq!(counts.len(), 3);
+    assert!(counts.values().all(|&n| n > 850 && n < 1150));
+}
+
+fn build_matrix(arena: &impl Arena) -> DancingLinksMatrix<'_, usize> {
+    MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6])
+        .add_row([1, 3, 5])
+        .add_row([2, 4, 6])
+        .build(arena)
+}
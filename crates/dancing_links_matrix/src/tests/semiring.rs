@@ -0,0 +1,127 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    MatrixBuilder, MemoCounter, Polynomial, Semiring, WeightedCounter,
+    tests::utils::{BumpArena, dominoes},
+};
+
+/// Returns `x` for the vertical dominoes and `1` for the horizontal ones, in the order
+/// the rows are added by `dominoes`.
+fn vertical_marks(rows: usize, cols: usize) -> Vec<Polynomial> {
+    let mut weights = Vec::new();
+
+    for (r, c) in (0..rows).cartesian_product(0..cols) {
+        if c + 1 < cols {
+            weights.push(Polynomial::one());
+        }
+        if r + 1 < rows {
+            weights.push(Polynomial::x());
+        }
+    }
+
+    weights
+}
+
+#[test]
+fn test_polynomial() {
+    let p = Polynomial::new([1, 2]);
+    let q = Polynomial::new([0, 1, 0, 0]);
+
+    assert_eq!(q.coefficients(), &[0, 1]);
+    assert_eq!(p.add(&q), Polynomial::new([1, 3]));
+    assert_eq!(p.mul(&q), Polynomial::new([0, 1, 2]));
+    assert_eq!(p.mul(&Polynomial::zero()), Polynomial::zero());
+    assert_eq!(p.coefficient(5), 0);
+}
+
+#[test]
+fn test_generating_function() {
+    let arena: BumpArena = Bump::new().into();
+    let counter = WeightedCounter::new(dominoes(&arena, 2, 4), vertical_marks(2, 4));
+
+    assert_eq!(counter.count(), Polynomial::new([1, 0, 3, 0, 1]));
+}
+
+#[test]
+fn test_generating_function_total() {
+    let arena: BumpArena = Bump::new().into();
+    let polynomial = WeightedCounter::new(dominoes(&arena, 6, 6), vertical_marks(6, 6)).count();
+
+    let arena: BumpArena = Bump::new().into();
+    let count = MemoCounter::new(dominoes(&arena, 6, 6)).count();
+
+    assert_eq!(polynomial.coefficients().iter().sum::<u128>(), count);
+    // The rows have an even number of squares, so an even number of vertical dominoes
+    // cross between each pair of adjacent rows.
+    assert!(
+        polynomial
+            .coefficients()
+            .iter()
+            .enumerate()
+            .all(|(k, &c)| k % 2 == 0 || c == 0)
+    );
+}
+
+#[test]
+fn test_polynomial_memory_limit() {
+    let arena: BumpArena = Bump::new().into();
+    let (expected, unlimited) =
+        WeightedCounter::new(dominoes(&arena, 4, 6), vertical_marks(4, 6)).count_with_stats();
+
+    // The coefficients of the cached polynomials are counted in the memory of the cache.
+    let arena: BumpArena = Bump::new().into();
+    let (_, unit) = WeightedCounter::new(
+        dominoes(&arena, 4, 6),
+        vec![1u128; vertical_marks(4, 6).len()],
+    )
+    .count_with_stats();
+    assert!(unlimited.peak_bytes > unit.peak_bytes);
+
+    let arena: BumpArena = Bump::new().into();
+    let limit = unlimited.peak_bytes / 4;
+    let (count, stats) = WeightedCounter::new(dominoes(&arena, 4, 6), vertical_marks(4, 6))
+        .with_memory_limit(limit)
+        .count_with_stats();
+
+    assert_eq!(count, expected);
+    assert!(stats.evictions > 0);
+    assert!(stats.peak_bytes <= limit);
+}
+
+#[test]
+fn test_unit_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 4, 6);
+    let rows = matrix.row_count();
+
+    assert_eq!(WeightedCounter::new(matrix, vec![1u128; rows]).count(), 281);
+}
+
+#[test]
+fn test_row_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .with_weight(2.0)
+        .add_row([3])
+        .with_weight(3.0)
+        .add_row([1])
+        .with_weight(5.0)
+        .add_row([2, 3])
+        .with_weight(7.0)
+        .build(&arena);
+
+    let (weight, stats) = WeightedCounter::from_row_weights(matrix).count_with_stats();
+    assert_eq!(weight, 2.0 * 3.0 + 5.0 * 7.0);
+    assert_eq!(stats.evictions, 0);
+}
+
+#[test]
+#[should_panic(expected = "There must be one weight for each row")]
+fn test_missing_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 2, 2);
+
+    WeightedCounter::new(matrix, [1u128]);
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+
+use crate::{ColumnSpec, MatrixBuilder, SetCoverSolver, tests::utils::BumpArena};
+
+#[test]
+fn solve_minimal_covers() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3, 4])
+        .add_row([1, 4])
+        .add_row([1, 2, 3])
+        .build(&arena);
+
+    let solver = SetCoverSolver::new(matrix);
+    let covers = row_sets(solver.solve_minimal().iter().map(|s| &s.solution_map));
+
+    let expected = HashSet::from([vec![1, 3], vec![2, 4], vec![3, 5], vec![4, 5]]);
+
+    assert_eq!(covers.len(), expected.len());
+    assert_eq!(HashSet::from_iter(covers), expected);
+}
+
+#[test]
+fn solve_minimum_cover() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([1, 2, 3, 4])
+        .add_row([3, 4, 5, 6])
+        .build(&arena);
+
+    let solver = SetCoverSolver::new(matrix);
+    let cover = solver.solve_minimum().unwrap();
+
+    assert_eq!(cover.solution_map.len(), 2);
+    assert!(cover.solution_map.contains_key(&4) || cover.solution_map.contains_key(&5));
+}
+
+#[test]
+fn solve_cover_impossible() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([1])
+        .build(&arena);
+
+    let solver = SetCoverSolver::new(matrix);
+    assert!(solver.solve_minimal().is_empty());
+    assert!(solver.solve_minimum().is_none());
+}
+
+#[test]
+fn solve_cover_secondary_columns() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([
+        ColumnSpec::primary(1),
+        ColumnSpec::primary(2),
+        ColumnSpec::secondary(3),
+    ])
+    .add_row([1, 3])
+    .add_row([2, 3])
+    .add_row([1, 2])
+    .build(&arena);
+
+    let solver = SetCoverSolver::new(matrix);
+    let covers = row_sets(solver.solve_minimal().iter().map(|s| &s.solution_map));
+
+    assert_eq!(covers, vec![vec![3]]);
+}
+
+fn row_sets<'a, V: 'a>(
+    maps: impl Iterator<Item = &'a std::collections::HashMap<usize, V>>,
+) -> Vec<Vec<usize>> {
+    maps.map(|m| {
+        let mut keys: Vec<_> = m.keys().copied().collect();
+        keys.sort();
+        keys
+    })
+    .collect()
+}
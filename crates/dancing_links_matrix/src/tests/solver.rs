@@ -83,9 +83,60 @@ fn solve_first_sol() {
     }
 }
 
-fn solve<'a>(
-    solver: &'a IterativeAlgorithmXSolver<'a, usize>,
-) -> Vec<HashMap<usize, Vec<&'a usize>>> {
+#[test]
+fn solve_cardinality() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6])
+        .build(&arena);
+
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_rows(2);
+    let solutions = solve(&solver);
+    assert_eq!(solutions.len(), 1);
+    check(solutions[0].keys(), [4, 5]);
+
+    let solver = solver.with_min_rows(3).with_max_rows(usize::MAX);
+    let solutions = solve(&solver);
+    assert_eq!(solutions.len(), 1);
+    check(solutions[0].keys(), [1, 2, 3]);
+
+    let solver = solver.with_rows(4);
+    assert!(solve(&solver).is_empty());
+
+    let solver = solver.with_min_rows(0).with_max_rows(3);
+    assert_eq!(solve(&solver).len(), 2);
+}
+
+#[test]
+fn solve_min_rows() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1])
+        .add_row([2])
+        .add_row([3])
+        .add_row([4, 5, 6])
+        .add_row([1, 2, 3])
+        .add_row([4])
+        .add_row([5, 6])
+        .build(&arena);
+
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false);
+    let solution = solver.solve_min_rows().unwrap();
+    check(solution.solution_map.keys(), [4, 5]);
+
+    let solver = solver.with_min_rows(3);
+    let solution = solver.solve_min_rows().unwrap();
+    check(solution.solution_map.keys(), [5, 6, 7]);
+
+    let solver = solver.with_min_rows(0).with_max_rows(1);
+    assert!(solver.solve_min_rows().is_none());
+}
+
+fn solve<'a>(solver: &IterativeAlgorithmXSolver<'a, usize>) -> Vec<HashMap<usize, Vec<&'a usize>>> {
     let solutions = solver.solve();
     solutions.into_iter().map(|v| v.solution_map).collect()
 }
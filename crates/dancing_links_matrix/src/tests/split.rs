@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    IterativeAlgorithmXSolver, MatrixBuilder, PartialResult, SubInstance,
+    tests::utils::{BumpArena, dominoes},
+};
+
+#[test]
+fn test_split_and_merge() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 4, 4);
+
+    let instances = SubInstance::split(&matrix, 8, 10);
+    assert!(instances.len() > 1 && instances.len() <= 8);
+
+    let results = instances
+        .iter()
+        .map(|instance| {
+            let arena: BumpArena = Bump::new().into();
+            let solver = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), true, false)
+                .with_assumptions(instance.rows.iter().copied());
+
+            PartialResult::from_solutions(&solver.solve())
+        })
+        .collect_vec();
+
+    assert!(results.iter().all(|result| result.count > 0));
+
+    let merged = PartialResult::merge(results);
+    assert_eq!(merged.count, 36);
+
+    // Every solution is found in exactly one sub-instance.
+    let unique: HashSet<_> = merged.solutions.iter().collect();
+    assert_eq!(unique.len(), 36);
+}
+
+#[test]
+fn test_split_depth() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 4, 4);
+
+    let instances = SubInstance::split(&matrix, 1000, 1);
+    assert_eq!(instances.len(), 2);
+    assert!(instances.iter().all(|instance| instance.rows.len() == 1));
+
+    assert_eq!(
+        SubInstance::split(&matrix, 1, 5),
+        vec![SubInstance { rows: vec![] }]
+    );
+}
+
+#[test]
+fn test_sub_instance_serialization() {
+    let instances = vec![
+        SubInstance { rows: vec![] },
+        SubInstance {
+            rows: vec![3, 17, 42],
+        },
+    ];
+
+    let mut text = Vec::new();
+    SubInstance::write_all(&instances, &mut text).unwrap();
+    assert_eq!(String::from_utf8(text.clone()).unwrap(), "\n3 17 42\n");
+
+    assert_eq!(SubInstance::read_all(text.as_slice()).unwrap(), instances);
+    assert!("1 x".parse::<SubInstance>().is_err());
+}
+
+#[test]
+fn test_partial_result_serialization() {
+    let result = PartialResult {
+        count: 2,
+        solutions: vec![vec![1, 4], vec![2, 3]],
+    };
+
+    let mut text = Vec::new();
+    result.write(&mut text).unwrap();
+    assert_eq!(
+        String::from_utf8(text.clone()).unwrap(),
+        "count 2\n1 4\n2 3\n"
+    );
+
+    assert_eq!(PartialResult::read(text.as_slice()).unwrap(), result);
+    assert!(PartialResult::read("2\n".as_bytes()).is_err());
+}
+
+#[test]
+fn test_conflicting_assumptions() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3])
+        .build(&arena);
+
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_assumptions([1, 2]);
+    assert!(solver.solve().is_empty());
+    assert!(solver.solve_min_rows().is_none());
+
+    let solver = solver.with_assumptions([1]);
+    assert_eq!(solver.solve().len(), 1);
+    assert_eq!(solver.solve_min_rows().unwrap().solution_map.len(), 2);
+}
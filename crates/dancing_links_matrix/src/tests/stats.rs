@@ -0,0 +1,86 @@
+use bumpalo::Bump;
+
+use crate::{
+    ColumnSpec, Diagnostic, IterativeAlgorithmXSolver, MatrixBuilder,
+    tests::utils::{BumpArena, dominoes},
+};
+
+#[test]
+fn test_stats() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 2, 3);
+    let stats = matrix.stats();
+
+    assert_eq!(stats.rows, 7);
+    assert_eq!(stats.primary_columns, 6);
+    assert_eq!(stats.secondary_columns, 0);
+    assert_eq!(stats.cells, 14);
+    assert!((stats.density - 14.0 / 42.0).abs() < 1e-9);
+
+    // The corners are in 2 dominoes, the middle cells in 3.
+    assert_eq!(stats.column_sizes.min, 2);
+    assert_eq!(stats.column_sizes.max, 3);
+    assert_eq!(
+        stats.column_sizes.histogram.into_iter().collect::<Vec<_>>(),
+        vec![(2, 4), (3, 2)]
+    );
+    assert_eq!(stats.row_lengths.mean, 2.0);
+    assert!(stats.memory_bytes > 0);
+    assert!(stats.diagnostics.is_empty());
+}
+
+#[test]
+fn test_diagnose() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([
+        ColumnSpec::primary("a"),
+        ColumnSpec::primary("b"),
+        ColumnSpec::primary("c"),
+        ColumnSpec::secondary("x"),
+        ColumnSpec::secondary("y"),
+    ])
+    .add_row(["a", "x"])
+    .add_row(["b"])
+    .add_row(["a", "x"])
+    .add_row(["b"])
+    .add_row(["x", "a"])
+    .build(&arena);
+
+    assert_eq!(
+        matrix.diagnose(),
+        vec![
+            Diagnostic::EmptyPrimaryColumn(&"c"),
+            Diagnostic::UnusedSecondaryColumn(&"y"),
+            Diagnostic::DuplicateRows(vec![1, 3, 5]),
+            Diagnostic::DuplicateRows(vec![2, 4]),
+        ]
+    );
+
+    let stats = matrix.stats();
+    assert_eq!(stats.diagnostics, matrix.diagnose());
+
+    let report = stats.to_string();
+    assert!(report.starts_with("rows: 5\ncolumns: 5 (3 primary, 2 secondary)\n"));
+    assert!(report.ends_with(
+        "primary column c is in no row, there is no solution\n\
+         secondary column y is in no row\n\
+         rows 1, 3, 5 have the same columns\n\
+         rows 2, 4 have the same columns"
+    ));
+}
+
+#[test]
+fn test_stats_during_search() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 2, 3);
+    let before = matrix.stats().to_string();
+
+    let mut solver = IterativeAlgorithmXSolver::new(matrix, true, false);
+    let mut stepper = solver.stepper();
+    for _ in 0..3 {
+        stepper.step();
+    }
+
+    assert!(!stepper.selection().is_empty());
+    assert_eq!(stepper.matrix().stats().to_string(), before);
+}
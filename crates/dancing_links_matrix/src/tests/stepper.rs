@@ -0,0 +1,104 @@
+use bumpalo::Bump;
+
+use crate::{
+    IterativeAlgorithmXSolver, MatrixBuilder, SearchEvent,
+    tests::utils::{BumpArena, dominoes},
+};
+
+#[test]
+fn test_events() {
+    use SearchEvent::*;
+
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2])
+        .add_row([1])
+        .add_row([2])
+        .add_row([1, 2])
+        .build(&arena);
+
+    let mut solver = IterativeAlgorithmXSolver::new(matrix, true, false);
+    let mut stepper = solver.stepper();
+    let events = std::iter::from_fn(|| stepper.step()).collect::<Vec<_>>();
+
+    assert_eq!(
+        events,
+        vec![
+            ChooseColumn { column: 2, rows: 2 },
+            TryRow { row: 2, depth: 1 },
+            ChooseColumn { column: 1, rows: 1 },
+            TryRow { row: 1, depth: 2 },
+            SolutionFound { rows: vec![2, 1] },
+            Backtrack { row: 1, depth: 2 },
+            Backtrack { row: 2, depth: 1 },
+            TryRow { row: 3, depth: 1 },
+            SolutionFound { rows: vec![3] },
+            Backtrack { row: 3, depth: 1 },
+        ]
+    );
+    assert_eq!(stepper.step(), None);
+}
+
+#[test]
+fn test_pause_and_drop() {
+    let arena: BumpArena = Bump::new().into();
+    let mut solver = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), true, false);
+
+    let mut stepper = solver.stepper();
+    let mut solutions = 0;
+
+    for _ in 0..50 {
+        if let Some(SearchEvent::SolutionFound { .. }) = stepper.step() {
+            solutions += 1;
+        }
+    }
+
+    assert!(solutions > 0);
+    assert!(!stepper.selection().is_empty());
+    drop(stepper);
+
+    // The matrix is restored when the stepper is dropped.
+    assert_eq!(solver.solve().len(), 36);
+
+    let mut stepper = solver.stepper();
+    let all = std::iter::from_fn(|| stepper.step())
+        .filter(|event| matches!(event, SearchEvent::SolutionFound { .. }))
+        .count();
+    assert_eq!(all, 36);
+}
+
+#[test]
+fn test_return_first() {
+    let arena: BumpArena = Bump::new().into();
+    let mut solver = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), true, true);
+
+    let mut stepper = solver.stepper();
+    let events = std::iter::from_fn(|| stepper.step()).collect::<Vec<_>>();
+
+    assert!(matches!(
+        events.last(),
+        Some(SearchEvent::SolutionFound { rows }) if rows.len() == 8
+    ));
+    assert!(stepper.selection().is_empty());
+}
+
+#[test]
+fn test_row_bounds() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2])
+        .add_row([1])
+        .add_row([2])
+        .add_row([1, 2])
+        .build(&arena);
+
+    let mut solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_rows(1);
+    let mut stepper = solver.stepper();
+
+    let solutions = std::iter::from_fn(|| stepper.step())
+        .filter_map(|event| match event {
+            SearchEvent::SolutionFound { rows } => Some(rows),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(solutions, vec![vec![3]]);
+}
@@ -0,0 +1,79 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    ColumnSpec, IterativeAlgorithmXSolver, StreamingBuilder,
+    tests::utils::{BumpArena, dominoes, dominoes_builder},
+};
+
+/// Builds the matrix of [`dominoes`] with a [`StreamingBuilder`].
+fn streamed_dominoes(
+    arena: &BumpArena,
+    rows: usize,
+    cols: usize,
+) -> crate::DancingLinksMatrix<'_, usize> {
+    let lists = dominoes_builder(rows, cols).row_lists();
+    let cells = lists.iter().map(Vec::len).sum();
+
+    lists
+        .into_iter()
+        .fold(
+            StreamingBuilder::new(arena, 0..rows * cols).with_capacity(0, cells),
+            |builder, row| builder.add_sorted_row_index(row),
+        )
+        .build()
+}
+
+#[test]
+fn test_same_links() {
+    let arena: BumpArena = Bump::new().into();
+    let expected = dominoes(&arena, 3, 4);
+    let matrix = streamed_dominoes(&arena, 3, 4);
+
+    assert_eq!(format!("{matrix:?}"), format!("{expected:?}"));
+    assert_eq!(format!("{}", &matrix), format!("{}", &expected));
+}
+
+#[test]
+fn test_solve() {
+    let arena: BumpArena = Bump::new().into();
+    let solver = IterativeAlgorithmXSolver::new(streamed_dominoes(&arena, 4, 4), true, false);
+
+    assert_eq!(solver.solve().len(), 36);
+}
+
+#[test]
+fn test_secondary_columns_and_weights() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = StreamingBuilder::<_, &str>::new(
+        &arena,
+        [
+            ColumnSpec::primary("a"),
+            ColumnSpec::primary("b"),
+            ColumnSpec::secondary("x"),
+        ],
+    )
+    .add_row_index([3, 1])
+    .with_weight(2.5)
+    .add_row_index([2])
+    .build();
+
+    assert_eq!(matrix.row_count(), 2);
+    assert_eq!(matrix.column_count(), 3);
+    assert_eq!(matrix.row_weight(1), 2.5);
+    assert_eq!(
+        matrix.row(1).unwrap().names().collect_vec(),
+        vec![&"a", &"x"]
+    );
+    assert!(!matrix.column(3).unwrap().is_primary());
+
+    let solutions = IterativeAlgorithmXSolver::new(matrix, true, false).solve();
+    assert_eq!(solutions.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Column 4 does not exist")]
+fn test_unknown_column() {
+    let arena: BumpArena = Bump::new().into();
+    StreamingBuilder::new(&arena, [1, 2, 3]).add_row_index([1, 4]);
+}
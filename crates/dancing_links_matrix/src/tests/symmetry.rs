@@ -0,0 +1,205 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{
+    Arena, ColumnSpec, DancingLinksMatrix, IterativeAlgorithmXSolver, MatrixBuilder, Symmetry,
+    SymmetryError, SymmetryGroup, tests::utils::BumpArena,
+};
+
+fn queen_columns(i: usize, j: usize, n: usize) -> [usize; 4] {
+    [i, n + j, 2 * n + i + j, 5 * n - 2 - i + j].map(|v| v + 1)
+}
+
+fn queens(arena: &impl Arena, n: usize) -> DancingLinksMatrix<'_, usize> {
+    let columns = (1..=6 * n - 2).map(|c| {
+        if c <= 2 * n {
+            ColumnSpec::primary(c)
+        } else {
+            ColumnSpec::secondary(c)
+        }
+    });
+
+    let mut builder = MatrixBuilder::from_iterable(columns);
+    for (i, j) in (0..n).cartesian_product(0..n) {
+        builder = builder.add_sorted_row_index(queen_columns(i, j, n));
+    }
+
+    builder.build(arena)
+}
+
+/// Creates the symmetry of the board mapping the square `(i, j)` to `map(i, j)`, where the
+/// `k`-th column of a queen is mapped to the `order[k]`-th column of its image.
+fn board_symmetry(
+    n: usize,
+    map: impl Fn(usize, usize) -> (usize, usize),
+    order: [usize; 4],
+) -> Symmetry {
+    let mut rows = vec![0; n * n];
+    let mut columns = vec![0; 6 * n - 2];
+
+    for (i, j) in (0..n).cartesian_product(0..n) {
+        let (mi, mj) = map(i, j);
+        rows[i * n + j] = mi * n + mj + 1;
+
+        let image = queen_columns(mi, mj, n);
+        for (k, c) in queen_columns(i, j, n).into_iter().enumerate() {
+            columns[c - 1] = image[order[k]];
+        }
+    }
+
+    // Diagonals with a single square are not covered by the mapping above.
+    for (c, image) in columns.iter_mut().enumerate() {
+        if *image == 0 {
+            *image = c + 1;
+        }
+    }
+
+    Symmetry::from_rows(rows).with_columns(columns)
+}
+
+fn board_symmetries(n: usize) -> [Symmetry; 2] {
+    [
+        board_symmetry(n, |i, j| (j, n - 1 - i), [1, 0, 3, 2]),
+        board_symmetry(n, |i, j| (i, n - 1 - j), [0, 1, 3, 2]),
+    ]
+}
+
+#[test]
+fn test_group_size() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = queens(&arena, 5);
+
+    let group = SymmetryGroup::generate(&matrix, board_symmetries(5)).unwrap();
+    assert_eq!(group.len(), 8);
+}
+
+#[test]
+fn test_queens_orbits() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = queens(&arena, 8);
+
+    let group = SymmetryGroup::generate(&matrix, board_symmetries(8)).unwrap();
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_symmetries(group);
+
+    let sizes = solver
+        .solve_orbits()
+        .iter()
+        .map(|orbit| orbit.size)
+        .sorted()
+        .collect_vec();
+
+    assert_eq!(sizes.len(), 12);
+    assert_eq!(sizes.iter().sum::<usize>(), 92);
+    assert_eq!(sizes[0], 4);
+    assert!(sizes[1..].iter().all(|&size| size == 8));
+}
+
+#[test]
+fn test_representatives_are_canonical() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = queens(&arena, 6);
+
+    let group = SymmetryGroup::generate(&matrix, board_symmetries(6)).unwrap();
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false).with_symmetries(group);
+
+    let orbits = solver.solve_orbits();
+    assert_eq!(orbits.len(), 1);
+    assert_eq!(orbits[0].size, 4);
+
+    // The smallest of the 4 solutions of the 6-queens problem.
+    let rows = orbits[0]
+        .representative
+        .solution_map
+        .keys()
+        .copied()
+        .sorted()
+        .collect_vec();
+    assert_eq!(rows, vec![2, 10, 18, 19, 27, 35]);
+}
+
+#[test]
+fn test_without_symmetries() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = queens(&arena, 6);
+
+    let orbits = IterativeAlgorithmXSolver::new(matrix, true, false).solve_orbits();
+    assert_eq!(orbits.len(), 4);
+    assert!(orbits.iter().all(|orbit| orbit.size == 1));
+}
+
+#[test]
+fn test_orbits_with_row_bounds() {
+    let arena: BumpArena = Bump::new().into();
+    let build = || {
+        MatrixBuilder::from_iterable([1, 2, 3, 4])
+            .add_row([1, 2])
+            .add_row([3, 4])
+            .add_row([1])
+            .add_row([2])
+            .add_row([3])
+            .add_row([4])
+            .build(&arena)
+    };
+
+    // Swapping the two halves of the columns, the covers with 2 and 4 rows are their own
+    // orbit, and the 2 covers with 3 rows form an orbit.
+    let symmetry = Symmetry::from_rows([2, 1, 5, 6, 3, 4]).with_columns([3, 4, 1, 2]);
+    let group = SymmetryGroup::generate(&build(), [symmetry]).unwrap();
+
+    for (rows, size) in [(2, 1), (3, 2), (4, 1)] {
+        let solver = IterativeAlgorithmXSolver::new(build(), true, false)
+            .with_symmetries(group.clone())
+            .with_rows(rows);
+
+        let orbits = solver.solve_orbits();
+        assert_eq!(orbits.len(), 1);
+        assert_eq!(orbits[0].representative.solution_map.len(), rows);
+        assert_eq!(orbits[0].size, size);
+    }
+}
+
+#[test]
+fn test_invalid_symmetries() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([3])
+        .add_row([1])
+        .add_row([2, 3])
+        .build(&arena);
+
+    let result = SymmetryGroup::generate(&matrix, [Symmetry::from_rows([1, 2])]);
+    assert_eq!(
+        result.unwrap_err(),
+        SymmetryError::InvalidRowPermutation { symmetry: 0 }
+    );
+
+    let result = SymmetryGroup::generate(
+        &matrix,
+        [Symmetry::from_rows([1, 2, 3, 4]).with_columns([1, 1, 3])],
+    );
+    assert_eq!(
+        result.unwrap_err(),
+        SymmetryError::InvalidColumnPermutation { symmetry: 0 }
+    );
+
+    let result = SymmetryGroup::generate(
+        &matrix,
+        [Symmetry::from_rows([2, 1, 3, 4]).with_columns([1, 2, 3])],
+    );
+    assert_eq!(
+        result.unwrap_err(),
+        SymmetryError::NotAnAutomorphism {
+            symmetry: 0,
+            row: 1
+        }
+    );
+
+    // Swapping the columns 1 and 3 maps {1, 2} to {2, 3} and {3} to {1}.
+    let group = SymmetryGroup::generate(
+        &matrix,
+        [Symmetry::from_rows([4, 3, 2, 1]).with_columns([3, 2, 1])],
+    )
+    .unwrap();
+    assert_eq!(group.len(), 2);
+}
@@ -1,6 +1,7 @@
 use bumpalo::Bump;
+use itertools::Itertools;
 
-use crate::Arena;
+use crate::{Arena, DancingLinksMatrix, MatrixBuilder, MatrixRowBuilder, Solution};
 
 pub(super) fn create_row<const N: usize>(v: [&str; N]) -> Vec<String> {
     v.iter().map(|v| v.to_string()).collect()
@@ -19,3 +20,34 @@ impl From<Bump> for BumpArena {
         BumpArena(value)
     }
 }
+
+/// Returns the indexes of the rows of a solution, sorted.
+pub(super) fn rows<T>(solution: &Solution<'_, T>) -> Vec<usize> {
+    solution.solution_map.keys().copied().sorted().collect()
+}
+
+/// Builds the matrix of the tilings of a `rows` x `cols` board with dominoes.
+pub(super) fn dominoes(
+    arena: &impl Arena,
+    rows: usize,
+    cols: usize,
+) -> DancingLinksMatrix<'_, usize> {
+    dominoes_builder(rows, cols).build(arena)
+}
+
+/// Returns the builder of [`dominoes`], before the matrix is built.
+pub(super) fn dominoes_builder(rows: usize, cols: usize) -> MatrixRowBuilder<usize> {
+    let mut builder = MatrixBuilder::from_iterable(0..rows * cols);
+
+    for (r, c) in (0..rows).cartesian_product(0..cols) {
+        let cell = r * cols + c;
+        if c + 1 < cols {
+            builder = builder.add_row([cell, cell + 1]);
+        }
+        if r + 1 < rows {
+            builder = builder.add_row([cell, cell + cols]);
+        }
+    }
+
+    builder
+}
@@ -0,0 +1,95 @@
+use bumpalo::Bump;
+
+use crate::{
+    ColumnSpec, DancingLinksMatrix, IterativeAlgorithmXSolver, MatrixBuilder,
+    tests::utils::{BumpArena, dominoes},
+    verify, verify_items,
+};
+
+fn matrix(arena: &BumpArena) -> DancingLinksMatrix<'_, &'static str> {
+    MatrixBuilder::from_iterable([
+        ColumnSpec::primary("a"),
+        ColumnSpec::primary("b"),
+        ColumnSpec::primary("c"),
+        ColumnSpec::secondary("x"),
+    ])
+    .add_row(["a", "x"])
+    .add_row(["b", "c"])
+    .add_row(["b", "x"])
+    .add_row(["c"])
+    .add_row(["a"])
+    .build(arena)
+}
+
+#[test]
+fn test_valid() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = matrix(&arena);
+
+    let report = verify(&matrix, [1, 2]);
+    assert!(report.is_valid());
+    assert_eq!(report.to_string(), "valid exact cover");
+
+    // Secondary columns may stay uncovered.
+    assert!(verify(&matrix, [5, 2]).is_valid());
+}
+
+#[test]
+fn test_invalid() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = matrix(&arena);
+
+    let report = verify(&matrix, [1, 3, 7]);
+    assert!(!report.is_valid());
+    assert_eq!(report.missing, vec![&"c"]);
+    assert_eq!(report.duplicated, vec![(&"x", 2)]);
+    assert_eq!(report.unknown_rows, vec![7]);
+    assert_eq!(
+        report.to_string(),
+        "column c is not covered\n\
+         column x is covered 2 times\n\
+         row 7 is not a row of the matrix"
+    );
+
+    let report = verify(&matrix, [2, 4]);
+    assert_eq!(report.missing, vec![&"a"]);
+    assert_eq!(report.duplicated, vec![(&"c", 2)]);
+}
+
+#[test]
+fn test_items() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = matrix(&arena);
+
+    assert!(verify_items(&matrix, [vec![&"x", &"a"], vec![&"c", &"b"]]).is_valid());
+
+    let report = verify_items(&matrix, [vec![&"a", &"x"], vec![&"b", &"c", &"d"]]);
+    assert!(report.missing.is_empty());
+    assert_eq!(report.unknown_rows, vec![1]);
+    assert_eq!(report.unknown_columns, vec![&"d"]);
+    assert_eq!(
+        report.to_string(),
+        "row 1 is not a row of the matrix\n\
+         column d is not a column of the matrix"
+    );
+
+    // The columns exist, but no row of the matrix has exactly these columns.
+    let report = verify_items(&matrix, [vec![&"a", &"b", &"c"]]);
+    assert_eq!(report.unknown_rows, vec![0]);
+    assert!(report.unknown_columns.is_empty());
+}
+
+#[test]
+fn test_solver_solutions() {
+    let arena: BumpArena = Bump::new().into();
+    let solutions = IterativeAlgorithmXSolver::new(dominoes(&arena, 4, 4), false, false).solve();
+
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 4, 4);
+
+    assert_eq!(solutions.len(), 36);
+    for solution in solutions {
+        let report = verify(&matrix, solution.solution_map.keys().copied());
+        assert!(report.is_valid(), "{report}");
+    }
+}
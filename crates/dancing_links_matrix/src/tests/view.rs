@@ -0,0 +1,78 @@
+use bumpalo::Bump;
+use itertools::Itertools;
+
+use crate::{ColumnSpec, IterativeAlgorithmXSolver, MatrixBuilder, tests::utils::BumpArena};
+
+#[test]
+fn test_views() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([
+        ColumnSpec::primary("a"),
+        ColumnSpec::primary("b"),
+        ColumnSpec::secondary("x"),
+    ])
+    .add_row(["b", "a"])
+    .add_row(["x", "b"])
+    .with_weight(2.0)
+    .build(&arena);
+
+    let rows = matrix.rows().collect_vec();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].id(), 1);
+    assert_eq!(rows[0].names().collect_vec(), vec![&"a", &"b"]);
+    assert_eq!(rows[1].names().collect_vec(), vec![&"b", &"x"]);
+    assert_eq!(rows[1].len(), 2);
+    assert_eq!(rows[1].weight(), 2.0);
+    assert!(rows.iter().all(|row| row.is_active()));
+    assert!(matrix.row(3).is_none());
+
+    let columns = matrix
+        .columns()
+        .map(|column| (*column.name(), column.is_primary(), column.size()))
+        .collect_vec();
+    assert_eq!(
+        columns,
+        vec![("a", true, 1), ("b", true, 2), ("x", false, 1)]
+    );
+    assert_eq!(matrix.column(2).unwrap().rows().collect_vec(), vec![1, 2]);
+    assert!(matrix.column(0).is_none());
+    assert!(matrix.column(4).is_none());
+
+    let row = matrix.row(2).unwrap();
+    assert_eq!(
+        row.columns().map(|column| column.index()).collect_vec(),
+        vec![2, 3]
+    );
+}
+
+#[test]
+fn test_views_during_search() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .add_row([3])
+        .add_row([2])
+        .build(&arena);
+
+    let mut solver = IterativeAlgorithmXSolver::new(matrix, true, false);
+    let mut stepper = solver.stepper();
+
+    // Column 1 has the fewest rows, so its only row is chosen first.
+    while stepper.selection().is_empty() {
+        stepper.step();
+    }
+    assert_eq!(stepper.selection(), vec![1]);
+
+    let matrix = stepper.matrix();
+    assert!(matrix.column(1).unwrap().is_covered());
+    assert!(!matrix.column(3).unwrap().is_covered());
+    assert_eq!(matrix.column(3).unwrap().rows().collect_vec(), vec![3]);
+    assert_eq!(matrix.column(3).unwrap().size(), 1);
+    assert!(!matrix.row(2).unwrap().is_active());
+    assert!(matrix.row(3).unwrap().is_active());
+    assert!(!matrix.row(4).unwrap().is_active());
+
+    // The rows themselves do not change.
+    assert_eq!(matrix.row(2).unwrap().names().collect_vec(), vec![&2, &3]);
+}
@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+
+use bumpalo::Bump;
+use itertools::Itertools;
+use rand::{SeedableRng, rngs::StdRng};
+
+use crate::{
+    IterativeAlgorithmXSolver, MatrixBuilder, Zdd, ZddRef,
+    tests::utils::{BumpArena, dominoes},
+};
+
+#[test]
+fn test_zdd_count() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6])
+        .build(&arena);
+
+    let zdd = Zdd::build(&matrix);
+    assert_eq!(zdd.count(), 2);
+}
+
+#[test]
+fn test_zdd_count_dominoes() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = dominoes(&arena, 3, 8);
+
+    let zdd = Zdd::build(&matrix);
+    assert_eq!(zdd.count(), 153);
+
+    let solver = IterativeAlgorithmXSolver::new(matrix, true, false);
+    assert_eq!(solver.solve().len(), 153);
+}
+
+#[test]
+fn test_zdd_no_solution() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3])
+        .add_row([1, 2])
+        .add_row([2, 3])
+        .build(&arena);
+
+    let zdd = Zdd::build(&matrix);
+    assert_eq!(zdd.count(), 0);
+    assert_eq!(zdd.root(), ZddRef::Bottom);
+    assert_eq!(zdd.sample(&mut StdRng::seed_from_u64(0)), None);
+}
+
+#[test]
+fn test_zdd_sample() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2, 3, 4, 5, 6])
+        .add_row([1, 2])
+        .add_row([3, 4])
+        .add_row([5, 6])
+        .add_row([2, 3, 5])
+        .add_row([1, 4, 6])
+        .build(&arena);
+
+    let zdd = Zdd::build(&matrix);
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut seen = HashMap::new();
+
+    for _ in 0..1000 {
+        let rows = zdd.sample(&mut rng).unwrap();
+        let rows: Vec<_> = rows.into_iter().sorted().collect();
+        *seen.entry(rows).or_insert(0) += 1;
+    }
+
+    assert_eq!(
+        seen.keys().cloned().collect::<HashSet<_>>(),
+        HashSet::from([vec![1, 2, 3], vec![4, 5]])
+    );
+    assert!(seen.values().all(|&n| n > 400));
+
+    let solution = matrix.solution_from_rows(zdd.sample(&mut rng).unwrap());
+    assert!(solution.solution_map.len() >= 2);
+}
+
+#[test]
+fn test_zdd_write() {
+    let arena: BumpArena = Bump::new().into();
+    let matrix = MatrixBuilder::from_iterable([1, 2])
+        .add_row([1])
+        .add_row([2])
+        .add_row([1, 2])
+        .build(&arena);
+
+    let zdd = Zdd::build(&matrix);
+    assert_eq!(zdd.count(), 2);
+
+    let mut out = Vec::new();
+    zdd.write(&mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    let lines = out.lines().collect_vec();
+    assert_eq!(lines[0], format!("root {}", zdd.root()));
+    assert_eq!(lines.len(), zdd.nodes().len() + 1);
+
+    let mut dot = Vec::new();
+    zdd.write_dot(&mut dot).unwrap();
+    assert!(String::from_utf8(dot).unwrap().starts_with("digraph zdd {"));
+}
@@ -0,0 +1,182 @@
+//! Checking that a set of rows is an exact cover, without running a solver.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+};
+
+use itertools::Itertools;
+
+use crate::{DancingLinksMatrix, matrix::CellIteratorDir};
+
+/// The report of [`verify`] or [`verify_items`].
+///
+/// The rows form an exact cover when the report is empty: see [`Self::is_valid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification<'r, T> {
+    /// The primary columns covered by no row.
+    pub missing: Vec<&'r T>,
+    /// The columns covered by more than one row, with the number of rows covering them.
+    pub duplicated: Vec<(&'r T, usize)>,
+    /// The rows that are not rows of the matrix: the indexes given to [`verify`], or the
+    /// positions of the rows given to [`verify_items`], starting from 0.
+    pub unknown_rows: Vec<usize>,
+    /// The columns given to [`verify_items`] that are not columns of the matrix.
+    pub unknown_columns: Vec<&'r T>,
+}
+
+impl<T> Verification<'_, T> {
+    /// Returns `true` if the rows form an exact cover.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty()
+            && self.duplicated.is_empty()
+            && self.unknown_rows.is_empty()
+            && self.unknown_columns.is_empty()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Verification<'_, T> {
+    /// Formats the report with one problem per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "valid exact cover");
+        }
+
+        let lines = self
+            .missing
+            .iter()
+            .map(|name| format!("column {name} is not covered"))
+            .chain(
+                self.duplicated
+                    .iter()
+                    .map(|(name, times)| format!("column {name} is covered {times} times")),
+            )
+            .chain(
+                self.unknown_rows
+                    .iter()
+                    .map(|row| format!("row {row} is not a row of the matrix")),
+            )
+            .chain(
+                self.unknown_columns
+                    .iter()
+                    .map(|name| format!("column {name} is not a column of the matrix")),
+            );
+
+        write!(f, "{}", lines.format("\n"))
+    }
+}
+
+/// Checks that the rows with the given indexes, numbered from 1, form an exact cover of
+/// `matrix`: every primary column is covered exactly once, and every secondary column at
+/// most once.
+///
+/// The check only reads the rows of the matrix, so it does not depend on the state of the
+/// dancing links.
+pub fn verify<'r, 'a: 'r, T>(
+    matrix: &'r DancingLinksMatrix<'a, T>,
+    rows: impl IntoIterator<Item = usize>,
+) -> Verification<'r, T> {
+    let mut unknown_rows = Vec::new();
+    let mut columns = Vec::new();
+
+    for row in rows {
+        match matrix.row_cell(row) {
+            Some(cell) => columns.extend(
+                matrix
+                    .iterate_cells(cell, CellIteratorDir::Right, true)
+                    .map(|cell| cell.column().index),
+            ),
+            None => unknown_rows.push(row),
+        }
+    }
+
+    report(matrix, columns, unknown_rows, Vec::new())
+}
+
+/// Checks that the rows, given as lists of columns, form an exact cover of `matrix`.
+///
+/// This verifies solutions coming from outside of the library, where rows are only known
+/// by their columns. A row whose columns are not exactly the columns of a row of the matrix
+/// is reported as unknown.
+pub fn verify_items<'r, 'a: 'r, T, R>(
+    matrix: &'r DancingLinksMatrix<'a, T>,
+    rows: impl IntoIterator<Item = R>,
+) -> Verification<'r, T>
+where
+    T: Eq + Hash,
+    R: IntoIterator<Item = &'r T>,
+{
+    let indexes: HashMap<&T, usize> = (1..=matrix.column_count())
+        .filter_map(|i| Some((matrix.columns[i].name.get_name()?, i)))
+        .collect();
+
+    let matrix_rows: HashSet<Vec<usize>> = (1..=matrix.row_count())
+        .map(|row| {
+            matrix
+                .iterate_cells(matrix.row_cell(row).unwrap(), CellIteratorDir::Right, true)
+                .map(|cell| cell.column().index)
+                .sorted()
+                .collect()
+        })
+        .collect();
+
+    let mut unknown_rows = Vec::new();
+    let mut unknown_columns = Vec::new();
+    let mut columns = Vec::new();
+
+    for (position, row) in rows.into_iter().enumerate() {
+        let mut row_columns = Vec::new();
+        let mut known = true;
+
+        for name in row {
+            match indexes.get(name) {
+                Some(&index) => row_columns.push(index),
+                None => {
+                    unknown_columns.push(name);
+                    known = false;
+                }
+            }
+        }
+
+        row_columns.sort_unstable();
+        if !known || !matrix_rows.contains(&row_columns) {
+            unknown_rows.push(position);
+        }
+
+        columns.extend(row_columns);
+    }
+
+    report(matrix, columns, unknown_rows, unknown_columns)
+}
+
+fn report<'r, T>(
+    matrix: &'r DancingLinksMatrix<'_, T>,
+    columns: Vec<usize>,
+    unknown_rows: Vec<usize>,
+    unknown_columns: Vec<&'r T>,
+) -> Verification<'r, T> {
+    let mut times = vec![0; matrix.column_count() + 1];
+    for column in columns {
+        times[column] += 1;
+    }
+
+    let name = |i: usize| matrix.columns[i].name.get_name().unwrap();
+
+    let missing = (1..=matrix.column_count())
+        .filter(|&i| matrix.columns[i].primary && times[i] == 0)
+        .map(name)
+        .collect();
+
+    let duplicated = (1..=matrix.column_count())
+        .filter(|&i| times[i] > 1)
+        .map(|i| (name(i), times[i]))
+        .collect();
+
+    Verification {
+        missing,
+        duplicated,
+        unknown_rows,
+        unknown_columns,
+    }
+}
@@ -0,0 +1,147 @@
+//! Read-only views of the rows and columns of a matrix.
+
+use std::fmt;
+
+use crate::{
+    DancingLinksMatrix,
+    cells::{ColumnRef, MatrixCellRef},
+    matrix::CellIteratorDir,
+};
+
+/// A row of a matrix, returned by [`DancingLinksMatrix::rows`] and
+/// [`DancingLinksMatrix::row`].
+pub struct RowView<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    cells: &'m [MatrixCellRef<'a, T>],
+}
+
+impl<'m, 'a, T> RowView<'m, 'a, T> {
+    pub(crate) fn new(matrix: &'m DancingLinksMatrix<'a, T>, row: usize) -> Option<Self> {
+        let first = matrix.row_cell(row)?;
+        let start = first.index;
+        let len = matrix.cells[start..]
+            .iter()
+            .take_while(|cell| cell.row == first.row)
+            .count();
+
+        Some(RowView {
+            matrix,
+            cells: &matrix.cells[start..start + len],
+        })
+    }
+
+    /// Returns the index of the row, numbered from 1 in the order the rows were added.
+    pub fn id(&self) -> usize {
+        self.cells[0].row.into()
+    }
+
+    /// Returns the number of columns of the row.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if the row has no column, which never happens for a built matrix.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the names of the columns of the row, in the order the columns were added.
+    pub fn names(&self) -> impl Iterator<Item = &'a T> + use<'m, 'a, T> {
+        self.cells
+            .iter()
+            .map(|cell| cell.column().name.get_name().unwrap())
+    }
+
+    /// Returns the columns of the row, in the order they were added.
+    pub fn columns(&self) -> impl Iterator<Item = ColumnView<'m, 'a, T>> + use<'m, 'a, T> {
+        let matrix = self.matrix;
+
+        self.cells.iter().map(move |cell| ColumnView {
+            matrix,
+            column: cell.column(),
+        })
+    }
+
+    /// Returns the weight of the row.
+    pub fn weight(&self) -> f64 {
+        self.matrix.row_weight(self.id())
+    }
+
+    /// Returns `true` if the row can still be chosen, none of its columns being covered.
+    pub fn is_active(&self) -> bool {
+        self.matrix.is_row_active(self.cells[0])
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RowView<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowView")
+            .field("id", &self.id())
+            .field("names", &self.names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// A column of a matrix, returned by [`DancingLinksMatrix::columns`] and
+/// [`DancingLinksMatrix::column`].
+pub struct ColumnView<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    column: ColumnRef<'a, T>,
+}
+
+impl<'m, 'a, T> ColumnView<'m, 'a, T> {
+    pub(crate) fn new(matrix: &'m DancingLinksMatrix<'a, T>, column: usize) -> Option<Self> {
+        (1..=matrix.column_count())
+            .contains(&column)
+            .then(|| ColumnView {
+                matrix,
+                column: matrix.columns[column],
+            })
+    }
+
+    /// Returns the index of the column, numbered from 1 in the order the columns were added.
+    pub fn index(&self) -> usize {
+        self.column.index
+    }
+
+    /// Returns the name of the column.
+    pub fn name(&self) -> &'a T {
+        self.column.name.get_name().unwrap()
+    }
+
+    /// Returns `true` if the column is primary, `false` if it is secondary.
+    pub fn is_primary(&self) -> bool {
+        self.column.primary
+    }
+
+    /// Returns `true` if the column is covered by a row chosen by a search in progress.
+    pub fn is_covered(&self) -> bool {
+        self.column.covered()
+    }
+
+    /// Returns the number of rows that can still cover the column.
+    ///
+    /// It is the number of rows of the column in a matrix without search in progress. The
+    /// size of a covered column is its size when it was covered.
+    pub fn size(&self) -> usize {
+        self.column.size()
+    }
+
+    /// Returns the indexes of the rows that can still cover the column, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = usize> + use<'a, T> {
+        self.matrix
+            .iterate_cells(self.column.cell(), CellIteratorDir::Down, false)
+            .map(|cell| cell.row.into())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ColumnView<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnView")
+            .field("index", &self.index())
+            .field("name", self.name())
+            .field("primary", &self.is_primary())
+            .field("size", &self.size())
+            .finish()
+    }
+}
@@ -0,0 +1,254 @@
+//! Zero-suppressed decision diagrams of the exact covers of a matrix.
+//!
+//! The diagram is built with Knuth's DXZ algorithm: Algorithm X is run memoizing each
+//! subproblem by the set of its active columns, so that subproblems reached along different
+//! paths are solved only once. The diagram represents the whole family of exact covers,
+//! which can then be counted, sampled and exported without enumerating them.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use rand::Rng;
+
+use crate::{
+    DancingLinksMatrix,
+    column_set::ColumnSet,
+    matrix::CellIteratorDir,
+    solver::{cover_row, uncover_row},
+};
+
+/// A reference to a node of a [`Zdd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZddRef {
+    /// The empty family.
+    Bottom,
+    /// The family containing only the empty set.
+    Top,
+    /// An internal node, identified by its index.
+    Node(usize),
+}
+
+/// An internal node of a [`Zdd`].
+///
+/// The node represents the union of the family `lo` and of the sets of the family `hi`
+/// with `row` added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZddNode {
+    /// The index of the row, numbered from 1.
+    pub row: usize,
+    pub lo: ZddRef,
+    pub hi: ZddRef,
+}
+
+/// A zero-suppressed decision diagram representing all the exact covers of a matrix.
+///
+/// Each path from the root to [`ZddRef::Top`] taking the `hi` branch of some nodes is an
+/// exact cover, made of the rows of those nodes.
+#[derive(Debug, Clone)]
+pub struct Zdd {
+    nodes: Vec<ZddNode>,
+    /// The number of sets represented by each node, indexed like `nodes`.
+    counts: Vec<u128>,
+    root: ZddRef,
+}
+
+impl Zdd {
+    /// Builds the diagram of all the exact covers of `matrix`.
+    pub fn build<T>(matrix: &DancingLinksMatrix<'_, T>) -> Zdd {
+        let mut builder = ZddBuilder {
+            matrix,
+            memo: HashMap::new(),
+            unique: HashMap::new(),
+            nodes: Vec::new(),
+        };
+
+        let root = builder.build();
+        let nodes = builder.nodes;
+
+        let mut counts = Vec::with_capacity(nodes.len());
+        for node in &nodes {
+            let count = count(&counts, node.lo) + count(&counts, node.hi);
+            counts.push(count);
+        }
+
+        Zdd {
+            nodes,
+            counts,
+            root,
+        }
+    }
+
+    /// Returns the root of the diagram.
+    pub fn root(&self) -> ZddRef {
+        self.root
+    }
+
+    /// Returns the internal nodes of the diagram.
+    ///
+    /// The children of a node always come before it.
+    pub fn nodes(&self) -> &[ZddNode] {
+        &self.nodes
+    }
+
+    /// Returns the number of exact covers.
+    pub fn count(&self) -> u128 {
+        count(&self.counts, self.root)
+    }
+
+    /// Returns an exact cover, as a list of row indexes, chosen uniformly at random among
+    /// all the exact covers.
+    ///
+    /// Returns `None` if there is no exact cover.
+    pub fn sample(&self, rng: &mut impl Rng) -> Option<Vec<usize>> {
+        if self.count() == 0 {
+            return None;
+        }
+
+        let mut rows = Vec::new();
+        let mut current = self.root;
+
+        while let ZddRef::Node(index) = current {
+            let node = self.nodes[index];
+            let hi = count(&self.counts, node.hi);
+
+            if rng.gen_range(0..self.counts[index]) < hi {
+                rows.push(node.row);
+                current = node.hi;
+            } else {
+                current = node.lo;
+            }
+        }
+
+        Some(rows)
+    }
+
+    /// Writes the diagram in a textual format.
+    ///
+    /// The first line contains the root, then each line contains a node as
+    /// `index row lo hi`, where `⊥` and `⊤` are written as `0` and `1` and the
+    /// nodes are numbered from 2.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "root {}", self.root)?;
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            writeln!(
+                writer,
+                "{} {} {} {}",
+                ZddRef::Node(i),
+                node.row,
+                node.lo,
+                node.hi
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the diagram in the Graphviz DOT format.
+    ///
+    /// Dashed edges are `lo` branches, solid edges are `hi` branches.
+    pub fn write_dot(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "digraph zdd {{")?;
+        writeln!(writer, "  0 [shape=box, label=\"⊥\"];")?;
+        writeln!(writer, "  1 [shape=box, label=\"⊤\"];")?;
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let id = ZddRef::Node(i);
+            writeln!(writer, "  {id} [label=\"{}\"];", node.row)?;
+            writeln!(writer, "  {id} -> {} [style=dashed];", node.lo)?;
+            writeln!(writer, "  {id} -> {};", node.hi)?;
+        }
+
+        writeln!(writer, "}}")
+    }
+
+    /// Saves the diagram to a file, using the format of [`Self::write`].
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+}
+
+impl fmt::Display for ZddRef {
+    /// Formats the reference as a number, `0` and `1` being the terminal nodes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZddRef::Bottom => 0.fmt(f),
+            ZddRef::Top => 1.fmt(f),
+            ZddRef::Node(index) => (index + 2).fmt(f),
+        }
+    }
+}
+
+fn count(counts: &[u128], node: ZddRef) -> u128 {
+    match node {
+        ZddRef::Bottom => 0,
+        ZddRef::Top => 1,
+        ZddRef::Node(index) => counts[index],
+    }
+}
+
+struct ZddBuilder<'m, 'a, T> {
+    matrix: &'m DancingLinksMatrix<'a, T>,
+    /// The diagram of each subproblem already solved, keyed by its active columns.
+    memo: HashMap<ColumnSet, ZddRef>,
+    /// The nodes already created, to avoid duplicates.
+    unique: HashMap<ZddNode, usize>,
+    nodes: Vec<ZddNode>,
+}
+
+impl<T> ZddBuilder<'_, '_, T> {
+    fn build(&mut self) -> ZddRef {
+        let matrix = self.matrix;
+
+        if matrix.all_covered() {
+            return ZddRef::Top;
+        }
+
+        let key = ColumnSet::active(matrix);
+        if let Some(&node) = self.memo.get(&key) {
+            return node;
+        }
+
+        let column = matrix.min_column();
+        let mut result = ZddRef::Bottom;
+
+        if !column.empty() {
+            matrix.cover(column);
+
+            for row in matrix.iterate_cells(column.cell(), CellIteratorDir::Down, false) {
+                cover_row(matrix, row);
+                let hi = self.build();
+                uncover_row(matrix, row);
+
+                if hi != ZddRef::Bottom {
+                    result = self.node(ZddNode {
+                        row: row.row.into(),
+                        lo: result,
+                        hi,
+                    });
+                }
+            }
+
+            matrix.uncover(column);
+        }
+
+        self.memo.insert(key, result);
+        result
+    }
+
+    fn node(&mut self, node: ZddNode) -> ZddRef {
+        let index = *self.unique.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        });
+
+        ZddRef::Node(index)
+    }
+}
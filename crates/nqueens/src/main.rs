@@ -50,8 +50,8 @@ fn build_matrix<'a>(n: usize, arena: &'a impl Arena) -> DancingLinksMatrix<'a, S
 }
 
 #[time("info")]
-fn solve<'a>(matrix: DancingLinksMatrix<'a, String>, n: usize) {
-    let solver = IterativeAlgorithmXSolver::new(matrix, true, true);
+fn solve<'a>(matrix: DancingLinksMatrix<'a, String>, n: usize, args: &CommonArgs) {
+    let solver = args.configure(IterativeAlgorithmXSolver::new(matrix, true, true));
     let solutions = solver.solve();
 
     match solutions.into_iter().next() {
@@ -99,5 +99,5 @@ fn main() {
     let n = args.n;
     let arena: BumpArena = Bump::new().into();
     let matrix = build_matrix(n, &arena);
-    solve(matrix, n);
+    solve(matrix, n, &args.common_args);
 }
@@ -107,8 +107,8 @@ fn print_sol(sol: &Solution<String>) {
 }
 
 #[time("info")]
-fn solve<'a>(matrix: DancingLinksMatrix<'a, String>) {
-    let solver = IterativeAlgorithmXSolver::new(matrix, true, true);
+fn solve<'a>(matrix: DancingLinksMatrix<'a, String>, args: &CommonArgs) {
+    let solver = args.configure(IterativeAlgorithmXSolver::new(matrix, true, true));
     let solutions = solver.solve();
 
     match solutions.into_iter().next() {
@@ -195,5 +195,5 @@ fn main() {
     let arena: BumpArena = Bump::new().into();
     let known = load_board(&path);
     let matrix = build_matrix(known, &arena);
-    solve(matrix);
+    solve(matrix, &args.common_args);
 }